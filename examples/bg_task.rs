@@ -13,7 +13,7 @@ fn main() {
     // We can quit by pressing `q`
     siv.add_global_callback('q', Cursive::quit);
 
-    let async_view = AsyncView::new_with_bg_creator(
+    let async_view: AsyncView<TextView> = AsyncView::new_with_bg_creator(
         &mut siv,
         move || {
             // this function is executed in a background thread, so we can block
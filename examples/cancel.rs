@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use cursive::views::{Dialog, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::AsyncView;
+
+fn main() {
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    let async_view = AsyncView::new_with_cancellable_bg_creator(
+        &mut siv,
+        move |token| {
+            // pretend to do some heavy work, which can be aborted on the way
+            for _ in 0..20 {
+                if token.wait_timeout(Duration::from_millis(500)) {
+                    return Err("Cancelled".to_string());
+                }
+            }
+
+            Ok("Finished loading, nobody pressed escape!")
+        },
+        TextView::new,
+    )
+    // pressing `Esc` while loading cancels the background task
+    .with_cancellable()
+    .with_width(40);
+
+    let dialog = Dialog::around(async_view).button("Ok", |s| s.quit());
+    siv.add_layer(dialog);
+    siv.run();
+}
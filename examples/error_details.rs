@@ -0,0 +1,39 @@
+use std::thread;
+use std::time::Duration;
+
+use cursive::views::{Dialog, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::AsyncView;
+
+fn main() {
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    let async_view = AsyncView::new_with_bg_creator(
+        &mut siv,
+        move || {
+            thread::sleep(Duration::from_secs(2));
+
+            // the first line is revealed by the error animation, the rest can be
+            // expanded by pressing enter
+            let trace = (0..20)
+                .map(|depth| format!("  at parse_record (records.rs:{})", 100 + depth))
+                .collect::<Vec<_>>()
+                .join("\n");
+            Err::<String, _>(format!(
+                "Could not parse the records: unexpected end of file\n{}",
+                trace
+            ))
+        },
+        TextView::new,
+    )
+    .with_width(40)
+    .with_height(12);
+
+    let dialog = Dialog::around(async_view).button("Ok", |s| s.quit());
+
+    siv.add_layer(dialog);
+    siv.run();
+}
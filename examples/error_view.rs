@@ -0,0 +1,45 @@
+use std::thread;
+use std::time::Duration;
+
+use cursive::view::{Nameable, Resizable, Scrollable};
+use cursive::views::{Dialog, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::AsyncView;
+
+fn main() {
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    let mut attempt = 0;
+    let async_view = AsyncView::new_retryable_with_bg_creator(
+        &mut siv,
+        move |_token| {
+            attempt += 1;
+            thread::sleep(Duration::from_secs(2));
+
+            if attempt < 2 {
+                Err("Connection refused\n\nThe server at example.com:443 did not accept the connection. Check your network and try again.".to_string())
+            } else {
+                Ok("Loaded on the second try!")
+            }
+        },
+        TextView::new,
+    )
+    // instead of the error animation, explain what went wrong and offer a way out
+    .with_error_view(|err| {
+        Dialog::around(TextView::new(err.clone()).scrollable())
+            .title("Could not load the content")
+            .button("Retry", |siv| {
+                siv.call_on_name("content", |view: &mut AsyncView<TextView>| view.retry());
+            })
+            .button("Quit", Cursive::quit)
+            .max_height(10)
+    })
+    .with_width(40)
+    .with_name("content");
+
+    siv.add_layer(async_view);
+    siv.run();
+}
@@ -0,0 +1,40 @@
+use std::thread;
+use std::time::Duration;
+
+use cursive::view::Nameable;
+use cursive::views::{Dialog, LinearLayout, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::AsyncView;
+
+fn main() {
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    let async_view = AsyncView::new_with_bg_creator(
+        &mut siv,
+        move || {
+            // the weather service is down again
+            thread::sleep(Duration::from_secs(2));
+            Err::<String, _>("Weather service unreachable".to_string())
+        },
+        TextView::new,
+    )
+    // an empty forecast is better than an error in the middle of the dashboard
+    .with_fallback(|_| TextView::new("No forecast available"))
+    // but let the user know in the status line
+    .with_fallback_callback(|siv, err| {
+        siv.call_on_name("status", |status: &mut TextView| {
+            status.set_content(format!("Error: {}", err))
+        });
+    })
+    .with_width(40);
+
+    let layout = LinearLayout::vertical()
+        .child(async_view)
+        .child(TextView::new("").with_name("status"));
+
+    siv.add_layer(Dialog::around(layout).button("Ok", |s| s.quit()));
+    siv.run();
+}
@@ -0,0 +1,70 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::Duration;
+
+use cursive::views::{Dialog, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::AsyncView;
+
+// A tiny timer future which is completed by a helper thread. Any other future
+// which does not depend on a specific runtime works just the same.
+struct Delay {
+    state: Arc<Mutex<(bool, Option<Waker>)>>,
+}
+
+impl Delay {
+    fn new(duration: Duration) -> Self {
+        let state = Arc::new(Mutex::new((false, None::<Waker>)));
+        let thread_state = Arc::clone(&state);
+
+        thread::spawn(move || {
+            thread::sleep(duration);
+            let mut state = thread_state.lock().unwrap();
+            state.0 = true;
+            if let Some(waker) = state.1.take() {
+                waker.wake();
+            }
+        });
+
+        Self { state }
+    }
+}
+
+impl Future for Delay {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.0 {
+            Poll::Ready(())
+        } else {
+            state.1 = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+async fn load_content() -> Result<String, String> {
+    // pretend to be waiting for the network
+    Delay::new(Duration::from_secs(5)).await;
+
+    Ok("Awaited the content! 🦀".to_string())
+}
+
+fn main() {
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    // no runtime needed, the future is driven by the async view itself
+    let async_view = AsyncView::from_future(&mut siv, load_content(), TextView::new);
+
+    let dialog = Dialog::around(async_view.with_width(40)).button("Ok", |s| s.quit());
+    siv.add_layer(dialog);
+
+    siv.run();
+}
@@ -0,0 +1,54 @@
+use std::thread;
+use std::time::Duration;
+
+use crossbeam::channel::{unbounded, TryRecvError};
+use cursive::views::{Dialog, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::{AsyncProgressState, AsyncProgressView, Notifier};
+
+fn main() {
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    // the notifier wakes up the view whenever there is new progress
+    let notifier = Notifier::new();
+    let (tx, rx) = unbounded();
+
+    let producer = notifier.clone();
+    thread::spawn(move || {
+        for step in 1..=10 {
+            thread::sleep(Duration::from_millis(500));
+            tx.send(step as f32 / 10.0).unwrap();
+            producer.notify();
+        }
+
+        // hang up and tell the view, so it notices we are done
+        drop(tx);
+        producer.notify();
+    });
+
+    let mut progress = 0.0;
+    let async_view: AsyncProgressView<TextView> =
+        AsyncProgressView::new_with_notifier(&mut siv, notifier, move || {
+            // this is only called after the producer notified us
+            loop {
+                match rx.try_recv() {
+                    Ok(value) => progress = value,
+                    Err(TryRecvError::Empty) => break AsyncProgressState::Pending(progress),
+                    Err(TryRecvError::Disconnected) => {
+                        break AsyncProgressState::Available(TextView::new(
+                            "Loaded without polling every frame! 🛌",
+                        ))
+                    }
+                }
+            }
+        })
+        .with_width(40);
+
+    let dialog = Dialog::around(async_view).button("Ok", |s| s.quit());
+    siv.add_layer(dialog);
+
+    siv.run();
+}
@@ -14,7 +14,7 @@ fn main() {
 
     let start = Instant::now();
 
-    let async_view = AsyncProgressView::new(&mut siv, move || {
+    let async_view: AsyncProgressView<TextView> = AsyncProgressView::new(&mut siv, move || {
         if start.elapsed() > Duration::from_secs(5) {
             AsyncProgressState::Available(TextView::new("🦀 Yay, the content has loaded! 🦀"))
         } else {
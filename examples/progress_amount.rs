@@ -0,0 +1,42 @@
+use std::thread;
+use std::time::Duration;
+
+use cursive::views::{Dialog, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::{AsyncProgressView, Unit};
+
+fn main() {
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    let async_view: AsyncProgressView<TextView> = AsyncProgressView::new_with_bg_creator(
+        &mut siv,
+        |reporter| {
+            let total = 120 * 1024 * 1024;
+            let chunk = 2 * 1024 * 1024;
+            reporter.set_message("Downloading archive.tar.gz");
+
+            let mut downloaded = 0;
+            while downloaded < total {
+                thread::sleep(Duration::from_millis(100));
+                downloaded += chunk;
+
+                // the size of the archive is only known after the first few chunks,
+                // until then an animation is shown instead of the bar
+                let known = downloaded > 10 * chunk;
+                reporter.set_amount(downloaded, known.then_some(total), Unit::Bytes);
+            }
+
+            Ok("Download complete 📦".to_string())
+        },
+        TextView::new,
+    )
+    .with_estimate()
+    .with_width(40);
+
+    let dialog = Dialog::around(async_view).button("Ok", |s| s.quit());
+    siv.add_layer(dialog);
+    siv.run();
+}
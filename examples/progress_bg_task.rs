@@ -0,0 +1,37 @@
+use std::thread;
+use std::time::Duration;
+
+use cursive::views::{Dialog, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::AsyncProgressView;
+
+fn main() {
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    let async_view: AsyncProgressView<TextView> = AsyncProgressView::new_with_bg_creator(
+        &mut siv,
+        |reporter| {
+            // this function is executed in a background thread, so we can block
+            // here and report our progress whenever we made some
+            let files = 50;
+            for file in 1..=files {
+                reporter.set_message(format!("Indexing file {}/{}", file, files));
+                thread::sleep(Duration::from_millis(100));
+                reporter.set(file as f32 / files as f32);
+            }
+
+            Ok(format!("Indexed {} files 🗂", files))
+        },
+        TextView::new,
+    )
+    // show how long indexing will take
+    .with_estimate()
+    .with_width(40);
+
+    let dialog = Dialog::around(async_view).button("Ok", |s| s.quit());
+    siv.add_layer(dialog);
+    siv.run();
+}
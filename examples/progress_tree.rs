@@ -0,0 +1,53 @@
+use std::thread;
+use std::time::Duration;
+
+use cursive::views::{Dialog, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::AsyncProgressView;
+
+fn main() {
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    let async_view: AsyncProgressView<TextView> = AsyncProgressView::new_with_bg_creator(
+        &mut siv,
+        |reporter| {
+            reporter.set_message("Building workspace");
+
+            // compiling takes longer than fetching, so it counts more towards the
+            // main bar
+            let workers = [("fetch", 1.0, 20), ("compile", 3.0, 60), ("docs", 1.0, 40)]
+                .iter()
+                .map(|&(name, weight, steps)| {
+                    let child = reporter.add_child(weight);
+                    child.set_message(name);
+                    thread::spawn(move || {
+                        // every task needs to find out what to do first
+                        child.set_indeterminate();
+                        thread::sleep(Duration::from_millis(1000));
+
+                        for step in 1..=steps {
+                            thread::sleep(Duration::from_millis(100));
+                            child.set(step as f32 / steps as f32);
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            for worker in workers {
+                worker.join().unwrap();
+            }
+
+            Ok("Workspace built 🛠".to_string())
+        },
+        TextView::new,
+    )
+    .with_estimate()
+    .with_width(50);
+
+    let dialog = Dialog::around(async_view).button("Ok", |s| s.quit());
+    siv.add_layer(dialog);
+    siv.run();
+}
@@ -0,0 +1,34 @@
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use cursive::views::{Dialog, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::AsyncView;
+
+fn main() {
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    let async_view: AsyncView<TextView> = AsyncView::new_retryable_with_bg_creator(
+        &mut siv,
+        move |_token| {
+            // pretend to query a slow server for its current state
+            thread::sleep(Duration::from_secs(1));
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+            Ok(format!("Server time: {}s", now.as_secs()))
+        },
+        TextView::new,
+    )
+    // query the server again every three seconds, keeping the last answer on
+    // screen with the loading animation in its top row while waiting
+    .with_refresh_interval(Duration::from_secs(3))
+    .with_refresh_paused_when_hidden()
+    .with_stale_while_revalidate()
+    .with_width(40);
+
+    let dialog = Dialog::around(async_view).button("Ok", |s| s.quit());
+    siv.add_layer(dialog);
+    siv.run();
+}
@@ -0,0 +1,49 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use cursive::view::Nameable;
+use cursive::views::{Dialog, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::{AsyncState, AsyncView};
+
+// pretend to fetch some data, which takes a while
+fn fetch(count: usize) -> impl FnMut() -> AsyncState<TextView> {
+    let start = Instant::now();
+
+    move || {
+        if start.elapsed() < Duration::from_secs(2) {
+            AsyncState::Pending
+        } else if count.is_multiple_of(3) {
+            // every third reload fails, the old content stays with an error badge
+            AsyncState::Error("Server unreachable".to_string())
+        } else {
+            AsyncState::Available(TextView::new(format!("Loaded {} times", count)))
+        }
+    }
+}
+
+fn main() {
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    let async_view = AsyncView::new(&mut siv, fetch(1))
+        // the old content stays visible while reloading, with the loading
+        // animation in its top row
+        .with_stale_while_revalidate()
+        .with_width(40)
+        .with_name("panel");
+
+    let count = AtomicUsize::new(1);
+    let dialog = Dialog::around(async_view)
+        .button("Reload", move |siv| {
+            let count = count.fetch_add(1, Ordering::Relaxed) + 1;
+            let mut panel = siv.find_name::<AsyncView<TextView>>("panel").unwrap();
+            panel.reload(siv, move || fetch(count));
+        })
+        .button("Quit", |s| s.quit());
+
+    siv.add_layer(dialog);
+    siv.run();
+}
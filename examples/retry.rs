@@ -0,0 +1,40 @@
+use std::thread;
+use std::time::Duration;
+
+use cursive::views::{Dialog, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::{AsyncView, RetryPolicy};
+
+fn main() {
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    // our very flaky network only answers every third request
+    let mut attempt = 0;
+    let async_view = AsyncView::new_retryable_with_bg_creator(
+        &mut siv,
+        move |_token| {
+            attempt += 1;
+            thread::sleep(Duration::from_secs(2));
+
+            if attempt % 3 == 0 {
+                Ok(format!("Loaded after {} attempts!", attempt))
+            } else {
+                Err(format!("Attempt {} failed, press r to retry", attempt))
+            }
+        },
+        TextView::new,
+    )
+    // the first failure is retried automatically after a second
+    .with_retry_policy(RetryPolicy::new(2).with_initial_delay(Duration::from_secs(1)))
+    // once all attempts failed, pressing `r` or clicking on the error runs the
+    // bg task again
+    .with_retryable()
+    .with_width(40);
+
+    let dialog = Dialog::around(async_view).button("Ok", |s| s.quit());
+    siv.add_layer(dialog);
+    siv.run();
+}
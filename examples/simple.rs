@@ -11,7 +11,7 @@ fn main() {
     siv.add_global_callback('q', Cursive::quit);
 
     let start_time = Instant::now();
-    let async_view = AsyncView::new(&mut siv, move || {
+    let async_view: AsyncView<TextView> = AsyncView::new(&mut siv, move || {
         // This function will be called several times.
         // It should signal `Available` when the view is available for drawing.
 
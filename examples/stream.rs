@@ -0,0 +1,40 @@
+use std::thread;
+use std::time::Duration;
+
+use cursive::views::{Dialog, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::{AsyncProgressView, ProgressEvent};
+
+fn main() {
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    // any stream works, here we feed a channel from a download thread
+    let (tx, rx) = futures::channel::mpsc::unbounded();
+
+    thread::spawn(move || {
+        let chunks = 20;
+        for chunk in 1..=chunks {
+            // "download" the next chunk
+            thread::sleep(Duration::from_millis(250));
+            tx.unbounded_send(ProgressEvent::Pending(chunk as f32 / chunks as f32))
+                .unwrap();
+        }
+
+        tx.unbounded_send(ProgressEvent::Available(format!(
+            "Downloaded all {} chunks! 📦",
+            chunks
+        )))
+        .unwrap();
+    });
+
+    let async_view: AsyncProgressView<TextView> =
+        AsyncProgressView::from_stream(&mut siv, rx, TextView::new).with_width(40);
+
+    let dialog = Dialog::around(async_view).button("Ok", |s| s.quit());
+    siv.add_layer(dialog);
+
+    siv.run();
+}
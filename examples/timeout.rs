@@ -2,9 +2,8 @@ use cursive::{
     views::{Dialog, TextView},
     Cursive, CursiveExt,
 };
-use cursive_async_view::{AsyncState, AsyncView};
-use std::sync::mpsc::{channel, TryRecvError};
-use std::time::{Duration, Instant};
+use cursive_async_view::AsyncView;
+use std::time::Duration;
 
 fn main() {
     let mut siv = Cursive::default();
@@ -12,42 +11,25 @@ fn main() {
     // We can quit by pressing `q`
     siv.add_global_callback('q', Cursive::quit);
 
-    // remember when we started calculation, to check for the timeout
-    let start_time = Instant::now();
-
-    // create channel to send our view over it
-    let (tx, rx) = channel();
-
-    // do some very instensive but important stuff here!
-    std::thread::spawn(move || {
-        // calc calc calc
-        std::thread::sleep(Duration::from_secs(20));
-
-        // as TextView implements `Send` we can send it between threads :)
-        tx.send(TextView::new("Content has loaded!")).ok();
-    });
-
-    let loading_view = AsyncView::new(&mut siv, move || {
-        // we want to inform that something is wrong after 5 seconds
-        if start_time.elapsed() > Duration::from_secs(5) {
-            // ideally, we should stop the calc thread here, but meh...
-            AsyncState::Error("Oh no, the view has timed out!".to_string())
-        } else {
-            // let's see if the view is available
-            match rx.try_recv() {
-                // yeet, it's there!
-                Ok(view) => AsyncState::Available(view),
-
-                // duh, still pending. Let's try another time...
-                Err(TryRecvError::Empty) => AsyncState::Pending,
-
-                // noooooo, my mighty channel
-                Err(TryRecvError::Disconnected) => {
-                    AsyncState::Error("Shoot, view creation thread exited...".to_string())
-                }
+    let loading_view = AsyncView::new_with_cancellable_bg_creator(
+        &mut siv,
+        move |token| {
+            // do some very instensive but important stuff here!
+            // calc calc calc, but stop as soon as nobody is waiting for us
+            if token.wait_timeout(Duration::from_secs(20)) {
+                return Err("Calculation has been cancelled".to_string());
             }
-        }
-    })
+
+            Ok("Content has loaded!")
+        },
+        TextView::new,
+    )
+    // we want to inform that something is wrong after 5 seconds, this also
+    // cancels the token of the view, stopping the calc thread
+    .with_timeout(Duration::from_secs(5))
+    .with_timeout_msg("Oh no, the view has timed out!")
+    // show how much time is left until we give up
+    .with_timeout_countdown()
     .with_width(40);
 
     // be fancy, add a dialog!
@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use cursive::views::{Dialog, LinearLayout, TextView};
+use cursive::{Cursive, CursiveExt};
+use cursive_async_view::{AsyncProgressView, AsyncView};
+
+fn main() {
+    // our services live on a tokio runtime, cursive keeps its own thread
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    let mut siv = Cursive::default();
+
+    // We can quit by pressing `q`
+    siv.add_global_callback('q', Cursive::quit);
+
+    let async_view: AsyncView<TextView> = AsyncView::spawn_on(
+        runtime.handle(),
+        &mut siv,
+        async {
+            tokio::time::sleep(Duration::from_secs(3)).await;
+            Ok("Fetched on tokio! 🗼")
+        },
+        TextView::new,
+    );
+
+    let progress_view: AsyncProgressView<TextView> = AsyncProgressView::spawn_on(
+        runtime.handle(),
+        &mut siv,
+        |reporter| async move {
+            for step in 1..=10 {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                reporter.set(step as f32 / 10.0);
+            }
+
+            Ok("Indexed on tokio! 📚")
+        },
+        TextView::new,
+    );
+
+    let layout = LinearLayout::vertical()
+        .child(async_view.with_width(40))
+        .child(progress_view.with_width(40));
+
+    siv.add_layer(Dialog::around(layout).button("Ok", |s| s.quit()));
+    siv.run();
+}
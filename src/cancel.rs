@@ -0,0 +1,93 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crossbeam::channel::{self, Receiver, RecvTimeoutError, Sender};
+
+/// A token to cooperatively cancel the work an async view is waiting for.
+///
+/// Every `AsyncView` and `AsyncProgressView` owns a token, which is cancelled as
+/// soon as the view does not wait for its content anymore, e.g. because the view
+/// has been dropped or the loading has finished. Background tasks should check
+/// the token regularly and exit early once it has been cancelled.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use cursive::views::TextView;
+/// use cursive::{Cursive, CursiveExt};
+/// use cursive_async_view::AsyncView;
+///
+/// let mut siv = Cursive::default();
+/// let async_view = AsyncView::new_with_cancellable_bg_creator(&mut siv, move |token| {
+///     for _ in 0..10 {
+///         // sleeps one second, but wakes up early when cancelled
+///         if token.wait_timeout(Duration::from_secs(1)) {
+///             return Err("Cancelled!".to_string());
+///         }
+///     }
+///
+///     Ok("Done calculating!")
+/// }, TextView::new);
+///
+/// siv.add_layer(async_view);
+/// // siv.run();
+/// ```
+#[derive(Clone)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    cancelled: AtomicBool,
+    // dropped on cancellation to wake up everyone waiting on `signal`
+    hangup: Mutex<Option<Sender<()>>>,
+    signal: Receiver<()>,
+}
+
+impl CancellationToken {
+    /// Create a new token which has not been cancelled yet.
+    pub fn new() -> Self {
+        let (hangup, signal) = channel::bounded(0);
+
+        Self {
+            inner: Arc::new(Inner {
+                cancelled: AtomicBool::new(false),
+                hangup: Mutex::new(Some(hangup)),
+                signal,
+            }),
+        }
+    }
+
+    /// Cancel the token and all of its clones. Cancelling an already
+    /// cancelled token does nothing.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::Release);
+        self.inner.hangup.lock().unwrap().take();
+    }
+
+    /// Whether the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Block the current thread until the token gets cancelled or `timeout`
+    /// has elapsed. Returns whether the token has been cancelled.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        match self.inner.signal.recv_timeout(timeout) {
+            Err(RecvTimeoutError::Timeout) => self.is_cancelled(),
+            _ => true,
+        }
+    }
+
+    /// A channel which gets disconnected once the token is cancelled.
+    pub(crate) fn signal(&self) -> &Receiver<()> {
+        &self.inner.signal
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
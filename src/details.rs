@@ -0,0 +1,159 @@
+use cursive_core::align::HAlign;
+use cursive_core::direction::Direction;
+use cursive_core::event::{AnyCb, Event, EventResult, Key};
+use cursive_core::utils::markup::StyledString;
+use cursive_core::view::{CannotFocus, Selector, View, ViewNotFound};
+use cursive_core::views::{ScrollView, TextView};
+use cursive_core::{Printer, Vec2};
+
+use crate::utils;
+
+/// The error animation of an async view, followed by the complete error message in
+/// a scrollable details section. The details are toggled with `Enter` once the
+/// error has been revealed, if the message does not fit into the summary line.
+pub(crate) struct ErrorDetails {
+    summary: TextView,
+    hint: TextView,
+    details: ScrollView<TextView>,
+    msg: String,
+    frame: StyledString,
+    summary_height: usize,
+    revealed: bool,
+    togglable: bool,
+    expanded: bool,
+}
+
+impl ErrorDetails {
+    pub(crate) fn new(msg: String) -> Self {
+        Self {
+            summary: TextView::new(""),
+            hint: TextView::new("").h_align(HAlign::Center),
+            details: ScrollView::new(TextView::new(msg.as_str())),
+            msg,
+            frame: StyledString::default(),
+            summary_height: 0,
+            revealed: false,
+            togglable: false,
+            expanded: false,
+        }
+    }
+
+    /// Show the next frame of the error animation, which is `width` characters wide.
+    /// The error counts as revealed once the animation is `done`, i.e. it returned
+    /// the index of the current frame as the next one.
+    pub(crate) fn set_frame(&mut self, frame: StyledString, width: usize, done: bool) {
+        self.revealed |= done;
+        self.togglable = utils::has_details(&self.msg, width);
+
+        if frame != self.frame {
+            self.summary.set_content(frame.clone());
+            self.frame = frame;
+        }
+    }
+
+    /// Whether the message has details which do not fit into the summary line.
+    pub(crate) fn has_details(&self) -> bool {
+        self.togglable
+    }
+
+    /// Whether the details are shown below the summary.
+    pub(crate) fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+
+    fn show_hint(&self) -> bool {
+        self.revealed && self.togglable
+    }
+
+    // the summary and the hint below
+    fn header_height(&self) -> usize {
+        self.summary_height + usize::from(self.show_hint())
+    }
+}
+
+impl View for ErrorDetails {
+    fn draw(&self, printer: &Printer) {
+        self.summary
+            .draw(&printer.cropped((printer.size.x, self.summary_height)));
+
+        if self.show_hint() {
+            let printer = printer.offset((0, self.summary_height));
+            self.hint.draw(&printer.cropped((printer.size.x, 1)));
+        }
+
+        if self.expanded {
+            self.details
+                .draw(&printer.offset((0, self.header_height())));
+        }
+    }
+
+    fn layout(&mut self, size: Vec2) {
+        self.summary_height = self.summary_height.min(size.y);
+        self.summary.layout((size.x, self.summary_height).into());
+        self.hint.layout((size.x, 1).into());
+
+        if self.expanded {
+            let height = size.y.saturating_sub(self.header_height());
+            self.details.layout((size.x, height).into());
+        }
+    }
+
+    fn needs_relayout(&self) -> bool {
+        true
+    }
+
+    fn required_size(&mut self, constraint: Vec2) -> Vec2 {
+        let summary = self.summary.required_size(constraint);
+        self.summary_height = summary.y;
+
+        if !self.show_hint() {
+            return summary;
+        }
+
+        self.hint.set_content(if self.expanded {
+            "Press Enter to hide details"
+        } else {
+            "Press Enter to show details"
+        });
+        let hint = self.hint.required_size(constraint);
+        let header = Vec2::new(summary.x.max(hint.x), self.header_height());
+
+        if !self.expanded {
+            return header;
+        }
+
+        // the details take the space left below the summary
+        let constraint = Vec2::new(constraint.x, constraint.y.saturating_sub(header.y));
+        let details = self.details.required_size(constraint);
+        Vec2::new(header.x.max(details.x), header.y + details.y)
+    }
+
+    fn on_event(&mut self, ev: Event) -> EventResult {
+        match ev {
+            Event::Key(Key::Enter) if self.show_hint() => {
+                self.expanded = !self.expanded;
+                EventResult::Consumed(None)
+            }
+            _ if self.expanded => self
+                .details
+                .on_event(ev.relativized((0, self.header_height()))),
+            _ => EventResult::Ignored,
+        }
+    }
+
+    fn call_on_any<'a>(&mut self, sel: &Selector, cb: AnyCb<'a>) {
+        self.details.call_on_any(sel, cb);
+    }
+
+    fn focus_view(&mut self, _: &Selector) -> Result<EventResult, ViewNotFound> {
+        Err(ViewNotFound)
+    }
+
+    fn take_focus(&mut self, _: Direction) -> Result<EventResult, CannotFocus> {
+        if self.togglable {
+            Ok(EventResult::Consumed(None))
+        } else {
+            Err(CannotFocus)
+        }
+    }
+}
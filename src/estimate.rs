@@ -0,0 +1,65 @@
+use std::time::{Duration, Instant};
+
+// the time constant of the moving average, older rates fade out within a few seconds
+const SMOOTHING: f32 = 3.0;
+
+/// Estimates the rate of progress and the time left from the progress values
+/// reported to an `AsyncProgressView`, using an exponential moving average of the
+/// rates between two reports.
+pub(crate) struct Estimator {
+    last: Option<(Instant, f32)>,
+    rate: Option<f32>,
+}
+
+impl Estimator {
+    pub(crate) fn new() -> Self {
+        Self {
+            last: None,
+            rate: None,
+        }
+    }
+
+    /// Record the progress `value` reported at `now`.
+    pub(crate) fn update(&mut self, value: f32, now: Instant) {
+        let (at, last) = match self.last {
+            Some((_, last)) if value == last => return,
+            Some(last) => last,
+            None => {
+                self.last = Some((now, value));
+                return;
+            }
+        };
+
+        if value < last {
+            // the progress went back, what has been measured so far is useless
+            self.rate = None;
+        } else {
+            let elapsed = now.saturating_duration_since(at).as_secs_f32();
+            if elapsed <= 0.0 {
+                return;
+            }
+
+            let sample = (value - last) / elapsed;
+            let alpha = 1.0 - (-elapsed / SMOOTHING).exp();
+            self.rate = Some(match self.rate {
+                Some(rate) => rate + alpha * (sample - rate),
+                None => sample,
+            });
+        }
+
+        self.last = Some((now, value));
+    }
+
+    /// The smoothed rate of progress in fractions per second.
+    pub(crate) fn rate(&self) -> Option<f32> {
+        self.rate
+    }
+
+    /// The time left at `now` until the progress reaches 1.
+    pub(crate) fn eta(&self, now: Instant) -> Option<Duration> {
+        let (at, value) = self.last?;
+        let rate = self.rate.filter(|rate| *rate > 0.0)?;
+        let left = (1.0 - value.min(1.0)) / rate - now.saturating_duration_since(at).as_secs_f32();
+        Duration::try_from_secs_f32(left.max(0.0)).ok()
+    }
+}
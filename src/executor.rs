@@ -0,0 +1,157 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+
+use futures_core::Stream;
+
+use crate::notify::Notifier;
+
+/// A waker which remembers that it has been woken and notifies the view, so
+/// the future gets polled again on the next poll of the view.
+struct WakeFlag(AtomicBool, Notifier);
+
+impl WakeFlag {
+    /// Create a raised flag, as every future or stream has to be polled at
+    /// least once to register its waker.
+    fn new(notifier: Notifier) -> Arc<Self> {
+        Arc::new(WakeFlag(AtomicBool::new(true), notifier))
+    }
+
+    fn raise(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    fn take(&self) -> bool {
+        self.0.swap(false, Ordering::AcqRel)
+    }
+}
+
+impl Wake for WakeFlag {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.raise();
+        self.1.notify();
+    }
+}
+
+/// A minimal single-future executor. It drives the future on the cursive
+/// thread whenever the poll callback of a view is invoked, so neither the
+/// future nor its output have to be `Send`.
+pub(crate) struct LocalFuture<O> {
+    future: Option<Pin<Box<dyn Future<Output = O>>>>,
+    flag: Arc<WakeFlag>,
+    waker: Waker,
+}
+
+impl<O> LocalFuture<O> {
+    pub(crate) fn new<F>(future: F, notifier: Notifier) -> Self
+    where
+        F: Future<Output = O> + 'static,
+    {
+        let flag = WakeFlag::new(notifier);
+
+        Self {
+            future: Some(Box::pin(future)),
+            waker: Waker::from(Arc::clone(&flag)),
+            flag,
+        }
+    }
+
+    /// Poll the wrapped future if it has been woken since the last poll.
+    /// Once the future completed, this will always return `Poll::Pending`.
+    pub(crate) fn poll(&mut self) -> Poll<O> {
+        if !self.flag.take() {
+            return Poll::Pending;
+        }
+
+        let future = match self.future {
+            Some(ref mut future) => future,
+            None => return Poll::Pending,
+        };
+
+        let mut cx = Context::from_waker(&self.waker);
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => {
+                self.future = None;
+                Poll::Ready(output)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// The stream counterpart of `LocalFuture`.
+pub(crate) struct LocalStream<I> {
+    stream: Option<Pin<Box<dyn Stream<Item = I>>>>,
+    flag: Arc<WakeFlag>,
+    waker: Waker,
+}
+
+impl<I> LocalStream<I> {
+    pub(crate) fn new<S>(stream: S, notifier: Notifier) -> Self
+    where
+        S: Stream<Item = I> + 'static,
+    {
+        let flag = WakeFlag::new(notifier);
+
+        Self {
+            stream: Some(Box::pin(stream)),
+            waker: Waker::from(Arc::clone(&flag)),
+            flag,
+        }
+    }
+
+    /// Poll the next item of the wrapped stream if it has been woken since
+    /// the last poll or the last poll yielded an item. Once the stream ended,
+    /// this will always return `Poll::Pending`.
+    pub(crate) fn poll_next(&mut self) -> Poll<Option<I>> {
+        if !self.flag.take() {
+            return Poll::Pending;
+        }
+
+        let stream = match self.stream {
+            Some(ref mut stream) => stream,
+            None => return Poll::Pending,
+        };
+
+        let mut cx = Context::from_waker(&self.waker);
+        match stream.as_mut().poll_next(&mut cx) {
+            Poll::Ready(Some(item)) => {
+                // the stream only wakes us after returning pending
+                self.flag.raise();
+                Poll::Ready(Some(item))
+            }
+            Poll::Ready(None) => {
+                self.stream = None;
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Aborts the wrapped tokio task once dropped, which happens as soon as the
+/// view polling the task is not interested in its result anymore.
+#[cfg(feature = "tokio")]
+pub(crate) struct AbortOnDrop<O>(pub(crate) tokio::task::JoinHandle<O>);
+
+#[cfg(feature = "tokio")]
+impl<O> Future for AbortOnDrop<O> {
+    type Output = Result<O, tokio::task::JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl<O> Drop for AbortOnDrop<O> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
@@ -1,20 +1,33 @@
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::future::Future;
+use std::panic;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::Poll;
 use std::thread;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
-use crossbeam::channel::{self, Receiver, Sender, TryRecvError};
-use cursive_core::align::HAlign;
+use crossbeam::channel::{self, Receiver, TryRecvError};
 use cursive_core::direction::Direction;
 use cursive_core::event::{AnyCb, Event, EventResult};
 use cursive_core::theme::PaletteColor;
 use cursive_core::utils::markup::StyledString;
 use cursive_core::view::{CannotFocus, Selector, View, ViewNotFound};
-use cursive_core::views::TextView;
-use cursive_core::{Cursive, Printer, Rect, Vec2};
+use cursive_core::{CbSink, Cursive, Printer, Rect, Vec2};
 use interpolation::Ease;
-use log::warn;
 use num::clamp;
-use send_wrapper::SendWrapper;
 
+use crate::cancel::CancellationToken;
+use crate::details::ErrorDetails;
+#[cfg(feature = "tokio")]
+use crate::executor::AbortOnDrop;
+use crate::executor::LocalFuture;
+use crate::lifecycle::{self, Change, Handled, Lifecycle, Phase, Polled};
+use crate::notify::{self, Notifier, PollMode};
+use crate::retry::RetryPolicy;
+use crate::unwind;
 use crate::utils;
 
 /// This struct represents the content of a single loading or error animation frame,
@@ -26,7 +39,8 @@ pub struct AnimationFrame {
     pub content: StyledString,
 
     /// The next `frame_idx` passed to the animation function when calculating
-    /// the next frame.
+    /// the next frame. An error animation returns the current `frame_idx` once it
+    /// is complete.
     pub next_frame_idx: usize,
 }
 
@@ -62,7 +76,7 @@ pub struct AnimationFrame {
 ///
 /// let mut siv = Cursive::default();
 /// let instant = Instant::now();
-/// let async_view = AsyncView::new(&mut siv, move || {
+/// let async_view: AsyncView<TextView> = AsyncView::new(&mut siv, move || {
 ///     if instant.elapsed() > Duration::from_secs(5) {
 ///         AsyncState::Available(
 ///             TextView::new("Yay!\n\nThe content has loaded!")
@@ -114,28 +128,63 @@ pub fn default_animation(width: usize, _height: usize, frame_idx: usize) -> Anim
     }
 }
 
-/// The default error animation for a `AsyncView`.
+/// The default error animation for a `AsyncView`. It reveals the first line of the
+/// error, shortened to the available width.
+///
+/// If the error does not fit into this line, e.g. a stack trace or the body of an
+/// HTTP response, the complete message can be expanded below by pressing `Enter`
+/// once the error has been revealed and the view has the focus. The expanded
+/// details scroll if they exceed the height of the view. This works with custom
+/// error animations as well, as soon as they are complete.
+///
+/// The message is measured and cut by its display width and grapheme clusters, so
+/// wide characters like CJK or emoji and zero-width characters like combining
+/// accents are centered correctly and never torn apart while being revealed:
+///
+/// ```
+/// use cursive_async_view::default_error;
+///
+/// let messages = ["接続できません 🔌", "Cafe\u{301} closed\u{200b}", "サーバーに接続できませんでした"];
+/// for msg in messages {
+///     for frame_idx in 0..=60 {
+///         // every frame fills the width exactly
+///         let frame = default_error(msg, 21, 1, 0, frame_idx);
+///         assert_eq!(frame.content.width(), 21);
+///     }
+/// }
+///
+/// // the final frame reveals the message, shortened if it is too wide, and
+/// // completes the animation by returning its own index
+/// let frame = default_error(messages[0], 21, 1, 0, 60);
+/// assert_eq!(frame.content.source(), "  接続できません 🔌  ");
+/// assert_eq!(frame.next_frame_idx, 60);
+/// let frame = default_error(messages[1], 21, 1, 0, 60);
+/// assert_eq!(frame.content.source(), "     Cafe\u{301} closed\u{200b}     ");
+/// let frame = default_error(messages[2], 21, 1, 0, 60);
+/// assert_eq!(frame.content.source(), "サーバーに接続できま…");
+/// ```
 ///
 /// # Creating your own error function
 ///
 /// As an example a very basic error function would look like this:
 ///
 /// ```
+/// use std::fmt::Display;
 /// use std::time::{Instant, Duration};
 /// use cursive::{Cursive, CursiveExt};
 /// use cursive::views::TextView;
 /// use cursive::utils::markup::StyledString;
 /// use cursive_async_view::{AsyncView, AsyncState, AnimationFrame};
 ///
-/// fn my_error_animation(
-///     msg: &str,
+/// fn my_error_animation<E: Display>(
+///     err: &E,
 ///     _width: usize,
 ///     _height: usize,
 ///     _error_idx: usize,
 ///     _frame_idx: usize,
 /// ) -> AnimationFrame {
 ///     AnimationFrame {
-///         content: StyledString::plain(msg),
+///         content: StyledString::plain(err.to_string()),
 ///         next_frame_idx: 0,
 ///     }
 /// }
@@ -154,12 +203,17 @@ pub fn default_animation(width: usize, _height: usize, frame_idx: usize) -> Anim
 /// // siv.run();
 /// ```
 ///
-/// This error function will just display the error message itself.
+/// This error function will just display the error message itself. The error can be
+/// of any type implementing `Display`, it is the error type of the `AsyncView`.
 ///
 /// The `width` and `height` prameters contain the maximum size the content may have
 /// (in characters). The initial `frame_idx` is 0.
-pub fn default_error(
-    msg: &str,
+///
+/// An error animation is complete once it returns the `frame_idx` it has been called
+/// with as `next_frame_idx`. The error details can only be expanded from then on, so
+/// the final frame has to keep returning its own index like the example above.
+pub fn default_error<E: Display + ?Sized>(
+    err: &E,
     width: usize,
     _height: usize,
     error_idx: usize,
@@ -169,8 +223,7 @@ pub fn default_error(
     let background = PaletteColor::HighlightInactive;
     let symbol = "━";
 
-    let offset = utils::repeat_str(" ", HAlign::Center.get_offset(msg.len(), width));
-    let mut msg = format!("{}{}{}", offset, msg, offset);
+    let msg = utils::center(&utils::summary_line(&err.to_string(), width), width);
 
     let duration = 60; // one second
     let durationf = duration as f64;
@@ -185,9 +238,9 @@ pub fn default_error(
     let factor = idxf / durationf;
     let begin_factor = clamp((factor % 1.0).circular_in_out(), 0.0, 1.0);
     let end_factor = clamp(((factor + 0.25) % 1.0).circular_in_out() * 2.0, 0.0, 1.0);
-    let mut begin = (begin_factor * width as f64) as usize;
+    let begin = (begin_factor * width as f64) as usize;
     let end = (end_factor * width as f64) as usize;
-    if frame_idx == cycle + duration {
+    if idx == cycle + duration {
         // Text can be fully shown
         return AnimationFrame {
             content: StyledString::plain(msg),
@@ -197,11 +250,7 @@ pub fn default_error(
 
     let mut result = StyledString::default();
     if end >= begin && idx > cycle {
-        if msg.as_str().get(0..begin).is_none() {
-            begin += 2;
-        }
-        msg.truncate(begin);
-        result.append_plain(msg);
+        result.append_plain(utils::cut(&msg, begin));
         result.append_styled(utils::repeat_str(symbol, end - begin), foreground);
         result.append_styled(utils::repeat_str(symbol, width - end), background);
     } else if end >= begin && idx <= cycle {
@@ -209,11 +258,7 @@ pub fn default_error(
         result.append_styled(utils::repeat_str(symbol, end - begin), foreground);
         result.append_styled(utils::repeat_str(symbol, width - end), background);
     } else if idx > cycle + duration / 2 {
-        if msg.as_str().get(0..begin).is_none() {
-            begin += 2
-        }
-        msg.truncate(begin);
-        result.append_plain(msg);
+        result.append_plain(utils::cut(&msg, begin));
         result.append_styled(utils::repeat_str(symbol, width - begin), foreground);
     } else {
         // Complete animation until text can be unveiled
@@ -228,18 +273,60 @@ pub fn default_error(
     }
 }
 
+/// The default cancel animation for a `AsyncView`. It reveals the message
+/// `Loading cancelled` in the same way `default_error` reveals an error.
+///
+/// A cancel animation is called with the same arguments as an error animation,
+/// except for the message. Wrap `default_error` to display a different message:
+///
+/// ```
+/// use std::time::{Instant, Duration};
+/// use cursive::{Cursive, CursiveExt};
+/// use cursive::views::TextView;
+/// use cursive_async_view::{default_error, AsyncView, AsyncState};
+///
+/// let mut siv = Cursive::default();
+/// let instant = Instant::now();
+/// let async_view: AsyncView<TextView> = AsyncView::new(&mut siv, move || {
+///     if instant.elapsed() > Duration::from_secs(5) {
+///         AsyncState::Available(TextView::new("Loaded!"))
+///     } else {
+///         AsyncState::Pending
+///     }
+/// })
+/// .with_cancellable()
+/// .with_cancel_fn(|width, height, cancel_idx, frame_idx| {
+///     default_error("Aborted by user", width, height, cancel_idx, frame_idx)
+/// });
+///
+/// siv.add_layer(async_view);
+/// // siv.run();
+/// ```
+pub fn default_cancel(
+    width: usize,
+    height: usize,
+    cancel_idx: usize,
+    frame_idx: usize,
+) -> AnimationFrame {
+    default_error("Loading cancelled", width, height, cancel_idx, frame_idx)
+}
+
 /// This enum is used in the ready_poll callback to tell the async view
 /// whether the view is already available, an error occured, or is still pending.
-pub enum AsyncState<V: View> {
+pub enum AsyncState<V: View, E = String> {
     /// The view of type `V` is now available and ready to be owned by the async view
     /// where it will get layouted and drawn instead of the loading animation.
     Available(V),
 
     /// Loading of the view failed with the given error.
-    Error(String),
+    Error(E),
 
     /// The view is not available yet, try again later.
     Pending,
+
+    /// Loading of the view has been cancelled, e.g. by the user. See
+    /// `AsyncView::cancel` for details.
+    Cancelled,
 }
 
 /// An `AsyncView` is a wrapper view that displays a loading screen, until the
@@ -268,7 +355,7 @@ pub enum AsyncState<V: View> {
 ///
 /// let mut siv = Cursive::default();
 /// let instant = Instant::now();
-/// let async_view = AsyncView::new(&mut siv, move || {
+/// let async_view: AsyncView<TextView> = AsyncView::new(&mut siv, move || {
 ///     // check if the view can be created
 ///     if instant.elapsed() > Duration::from_secs(10) {
 ///         AsyncState::Available(
@@ -307,7 +394,7 @@ pub enum AsyncState<V: View> {
 /// use cursive_async_view::AsyncView;
 ///
 /// let mut siv = Cursive::default();
-/// let async_view = AsyncView::new_with_bg_creator(&mut siv, move || {
+/// let async_view: AsyncView<TextView> = AsyncView::new_with_bg_creator(&mut siv, move || {
 ///     // this function is executed in a background thread, so we can block
 ///     // here as long as we like
 ///     thread::sleep(Duration::from_secs(10));
@@ -321,25 +408,45 @@ pub enum AsyncState<V: View> {
 /// ```
 ///
 /// The content will be displayed after 10 seconds.
-pub struct AsyncView<T: View> {
-    view: AsyncState<T>,
-    loading: TextView,
-    animation_fn: Box<dyn Fn(usize, usize, usize) -> AnimationFrame + Send + Sync + 'static>,
-    error_fn:
-        Box<dyn Fn(&str, usize, usize, usize, usize) -> AnimationFrame + Send + Sync + 'static>,
+pub struct AsyncView<T: View, E = String> {
+    core: Lifecycle<T, E>,
+    animation_fn: AnimationFn,
+    error_fn: ErrorFn<E>,
+    cancel_fn: CancelFn,
     width: Option<usize>,
     height: Option<usize>,
     pos: usize,
     error_idx: usize,
-    rx: Receiver<AsyncState<T>>,
-    error_sender: Sender<()>,
+    refresh_interval: Option<Duration>,
+    refresh_timer: CancellationToken,
+    refresh_due: Arc<AtomicBool>,
+    refresh_unfocused: bool,
+    refresh_hidden: bool,
+    focused: bool,
+    drawn: AtomicBool,
+}
+
+type AnimationFn = Box<dyn Fn(usize, usize, usize) -> AnimationFrame + Send + Sync + 'static>;
+type ErrorFn<E> =
+    Box<dyn Fn(&E, usize, usize, usize, usize) -> AnimationFrame + Send + Sync + 'static>;
+type CancelFn = Box<dyn Fn(usize, usize, usize, usize) -> AnimationFrame + Send + Sync + 'static>;
+
+impl<T: View, E> From<AsyncState<T, E>> for Polled<T, E> {
+    fn from(state: AsyncState<T, E>) -> Self {
+        match state {
+            AsyncState::Available(view) => Polled::Available(view),
+            AsyncState::Error(err) => Polled::Error(err),
+            AsyncState::Pending => Polled::Pending(None),
+            AsyncState::Cancelled => Polled::Cancelled,
+        }
+    }
 }
 
 lazy_static::lazy_static! {
     pub(crate) static ref FPS: Duration = Duration::from_secs(1) / 60;
 }
 
-impl<T: View> AsyncView<T> {
+impl<T: View, E: Display + Send + Sync + 'static> AsyncView<T, E> {
     /// Create a new `AsyncView` instance. The cursive reference is used
     /// to control the refresh rate of the terminal when the loading animation
     /// is running. In order to show the view, it has to be directly or indirectly
@@ -351,30 +458,127 @@ impl<T: View> AsyncView<T> {
     /// Instead use a dedicated thread for it as shown in the `bg_task` example.
     pub fn new<F>(siv: &mut Cursive, ready_poll: F) -> Self
     where
-        F: FnMut() -> AsyncState<T> + 'static,
+        E: From<String>,
+        F: FnMut() -> AsyncState<T, E> + 'static,
     {
-        // create communication channel between cursive event loop and
-        // this views layout code
-        let (tx, rx) = channel::unbounded();
-        let (error_tx, error_rx) = channel::bounded(1);
+        Self::with_poll_mode(siv, PollMode::Fps, CancellationToken::new(), ready_poll)
+    }
 
-        let instant = Instant::now();
-        Self::polling_cb(siv, instant, SendWrapper::new(tx), error_rx, ready_poll);
+    /// Create a new `AsyncView` instance which only calls `ready_poll` after
+    /// it has been woken by the given `notifier`. In order to show the view,
+    /// it has to be directly or indirectly added to a cursive layer like any
+    /// other view.
+    ///
+    /// Compared to `new`, the `ready_poll` function is not called on every
+    /// frame, but only once initially and then whenever new state has been
+    /// announced by calling `Notifier::notify`. The loading animation keeps
+    /// running on its own timer until the view has been loaded. See the
+    /// `Notifier` documentation for an example.
+    pub fn new_with_notifier<F>(siv: &mut Cursive, notifier: Notifier, ready_poll: F) -> Self
+    where
+        E: From<String>,
+        F: FnMut() -> AsyncState<T, E> + 'static,
+    {
+        let mode = PollMode::notified(siv.cb_sink(), notifier.clone());
+        let mut view = Self::with_poll_mode(siv, mode, CancellationToken::new(), ready_poll);
+        view.core.notifier = Some(notifier);
+        view
+    }
 
+    fn with_poll_mode<F>(
+        siv: &mut Cursive,
+        mode: PollMode,
+        token: CancellationToken,
+        ready_poll: F,
+    ) -> Self
+    where
+        E: From<String>,
+        F: FnMut() -> AsyncState<T, E> + 'static,
+    {
+        let ready_poll = lifecycle::poll_fn(ready_poll);
+        Self::with_lifecycle(Lifecycle::new(siv, mode, token, ready_poll))
+    }
+
+    fn with_lifecycle(core: Lifecycle<T, E>) -> Self {
         Self {
-            view: AsyncState::Pending,
-            loading: TextView::new(""),
+            core,
             animation_fn: Box::new(default_animation),
             error_fn: Box::new(default_error),
+            cancel_fn: Box::new(default_cancel),
             width: None,
             height: None,
             pos: 0,
             error_idx: 0,
-            rx,
-            error_sender: error_tx,
+            refresh_interval: None,
+            refresh_timer: CancellationToken::new(),
+            refresh_due: Arc::new(AtomicBool::new(false)),
+            refresh_unfocused: true,
+            refresh_hidden: true,
+            focused: false,
+            drawn: AtomicBool::new(false),
         }
     }
 
+    /// Create a new `AsyncView` instance which can be retried after loading
+    /// failed. The cursive reference is used to control the refresh rate of the
+    /// terminal when the loading animation is running. In order to show the view,
+    /// it has to be directly or indirectly added to a cursive layer like any
+    /// other view.
+    ///
+    /// The `factory` is called once initially and once for every retry. It
+    /// creates a fresh `ready_poll` function, which works like the one given to
+    /// `new`. See `with_retryable` for how to let the user retry.
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive::views::TextView;
+    /// use cursive_async_view::{AsyncView, AsyncState};
+    ///
+    /// let mut siv = Cursive::default();
+    /// let mut attempt = 0;
+    /// let async_view = AsyncView::new_retryable(&mut siv, move || {
+    ///     attempt += 1;
+    ///     let failing = attempt == 1;
+    ///     let start = Instant::now();
+    ///
+    ///     move || {
+    ///         if start.elapsed() < Duration::from_secs(2) {
+    ///             AsyncState::Pending
+    ///         } else if failing {
+    ///             AsyncState::Error("Flaky network, press r to retry".to_string())
+    ///         } else {
+    ///             AsyncState::Available(TextView::new("Second time's the charm!"))
+    ///         }
+    ///     }
+    /// })
+    /// .with_retryable();
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    pub fn new_retryable<F, P>(siv: &mut Cursive, mut factory: F) -> Self
+    where
+        E: From<String>,
+        F: FnMut() -> P + 'static,
+        P: FnMut() -> AsyncState<T, E> + 'static,
+    {
+        Self::with_loader(siv, move |_, _| (PollMode::Fps, factory()))
+    }
+
+    fn with_loader<L, P>(siv: &mut Cursive, mut loader: L) -> Self
+    where
+        E: From<String>,
+        L: FnMut(&CbSink, &CancellationToken) -> (PollMode, P) + 'static,
+        P: FnMut() -> AsyncState<T, E> + 'static,
+    {
+        let loader = move |sink: &CbSink, token: &CancellationToken| {
+            let (mode, ready_poll) = loader(sink, token);
+            (mode, lifecycle::poll_fn(ready_poll))
+        };
+        Self::with_lifecycle(Lifecycle::with_loader(siv, loader))
+    }
+
     /// Create a new `AsyncView` instance. The cursive reference is used
     /// to control the refresh rate of the terminal when the loading animation
     /// is running. In order to show the view, it has to be directly or indirectly
@@ -383,104 +587,608 @@ impl<T: View> AsyncView<T> {
     /// The `bg_task` function is executed on a background thread called
     /// `cursive-async-view::bg_task`. It should be used to produce data of
     /// type `D` which is converted to a view by the `view_creator` function.
-    pub fn new_with_bg_creator<F, C, D>(siv: &mut Cursive, bg_task: F, mut view_creator: C) -> Self
+    ///
+    /// The view can be retried, which runs a clone of `bg_task` on a new
+    /// background thread. Use `new_retryable_with_bg_creator` for a `bg_task`
+    /// which cannot be cloned.
+    pub fn new_with_bg_creator<F, C, D>(siv: &mut Cursive, bg_task: F, view_creator: C) -> Self
     where
+        E: From<String>,
         D: Send + 'static,
-        F: FnOnce() -> Result<D, String> + Send + 'static,
+        F: FnOnce() -> Result<D, E> + Clone + Send + 'static,
         C: FnMut(D) -> T + 'static,
     {
-        let (tx, rx) = channel::unbounded();
-
-        thread::Builder::new()
-            .name("cursive-async-view::bg_task".into())
-            .spawn(move || {
-                tx.send(bg_task()).unwrap();
-            })
-            .unwrap();
-
-        Self::new(siv, move || match rx.try_recv() {
-            Ok(Ok(data)) => AsyncState::Available(view_creator(data)),
-            Ok(Err(err)) => AsyncState::Error(err),
-            Err(TryRecvError::Empty) => AsyncState::Pending,
-            Err(TryRecvError::Disconnected) => {
-                AsyncState::Error("Internal error: bg_task disconnected unexpectedly!".to_string())
-            }
+        Self::new_with_cancellable_bg_creator(siv, move |_| bg_task(), view_creator)
+    }
+
+    /// Create a new `AsyncView` instance. The cursive reference is used
+    /// to control the refresh rate of the terminal when the loading animation
+    /// is running. In order to show the view, it has to be directly or indirectly
+    /// added to a cursive layer like any other view.
+    ///
+    /// Works like `new_with_bg_creator`, but the `bg_task` function receives
+    /// the `CancellationToken` of this view. The token is cancelled once the
+    /// view does not wait for the data anymore, e.g. because it has been
+    /// dropped. The `bg_task` should check the token regularly and return
+    /// early when it has been cancelled. See the `CancellationToken`
+    /// documentation for an example. Every retry runs a clone of `bg_task`
+    /// with a new token.
+    pub fn new_with_cancellable_bg_creator<F, C, D>(
+        siv: &mut Cursive,
+        bg_task: F,
+        view_creator: C,
+    ) -> Self
+    where
+        E: From<String>,
+        D: Send + 'static,
+        F: FnOnce(CancellationToken) -> Result<D, E> + Clone + Send + 'static,
+        C: FnMut(D) -> T + 'static,
+    {
+        let view_creator = Rc::new(RefCell::new(view_creator));
+
+        Self::with_loader(siv, move |_, token| {
+            let bg_task = bg_task.clone();
+            let bg_token = token.clone();
+            let rx = spawn_bg_task(move || bg_task(bg_token));
+
+            let view_creator = Rc::clone(&view_creator);
+            let view_creator = move |data| (*view_creator.borrow_mut())(data);
+            (PollMode::Fps, bg_ready_poll(rx, view_creator))
         })
     }
 
-    fn polling_cb<F>(
+    /// Create a new `AsyncView` instance which can be retried after loading
+    /// failed. The cursive reference is used to control the refresh rate of the
+    /// terminal when the loading animation is running. In order to show the view,
+    /// it has to be directly or indirectly added to a cursive layer like any
+    /// other view.
+    ///
+    /// Works like `new_with_cancellable_bg_creator`, but `bg_task` is an `FnMut`
+    /// which does not have to be cloned. It is run again on a new background
+    /// thread for every retry, see `with_retryable`. Every run receives a new
+    /// token. A retry waits for the previous run of `bg_task` to
+    /// return before running it again.
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// use cursive::views::TextView;
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive_async_view::AsyncView;
+    ///
+    /// let mut siv = Cursive::default();
+    /// let mut attempt = 0;
+    /// let async_view: AsyncView<TextView> = AsyncView::new_retryable_with_bg_creator(&mut siv, move |_token| {
+    ///     attempt += 1;
+    ///     thread::sleep(Duration::from_secs(2));
+    ///
+    ///     if attempt == 1 {
+    ///         Err("Flaky network, press r to retry".to_string())
+    ///     } else {
+    ///         Ok("Second time's the charm!")
+    ///     }
+    /// }, TextView::new)
+    /// .with_retryable();
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    pub fn new_retryable_with_bg_creator<F, C, D>(
         siv: &mut Cursive,
-        instant: Instant,
-        chan: SendWrapper<Sender<AsyncState<T>>>,
-        end_anim: Receiver<()>,
-        mut cb: F,
-    ) where
-        F: FnMut() -> AsyncState<T> + 'static,
+        bg_task: F,
+        view_creator: C,
+    ) -> Self
+    where
+        E: From<String>,
+        D: Send + 'static,
+        F: FnMut(CancellationToken) -> Result<D, E> + Send + 'static,
+        C: FnMut(D) -> T + 'static,
+    {
+        let bg_task = Arc::new(Mutex::new(bg_task));
+        let view_creator = Rc::new(RefCell::new(view_creator));
+
+        Self::with_loader(siv, move |_, token| {
+            let bg_task = Arc::clone(&bg_task);
+            let bg_token = token.clone();
+            let rx = spawn_bg_task(move || {
+                // a panic of the previous run does not keep the task from running again
+                let mut bg_task = bg_task.lock().unwrap_or_else(PoisonError::into_inner);
+                (*bg_task)(bg_token)
+            });
+
+            let view_creator = Rc::clone(&view_creator);
+            let view_creator = move |data| (*view_creator.borrow_mut())(data);
+            (PollMode::Fps, bg_ready_poll(rx, view_creator))
+        })
+    }
+
+    /// Create a new `AsyncView` instance. The cursive reference is used
+    /// to control the refresh rate of the terminal when the loading animation
+    /// is running. In order to show the view, it has to be directly or indirectly
+    /// added to a cursive layer like any other view.
+    ///
+    /// The `future` is driven to completion by a small built-in executor on
+    /// the cursive thread, so no particular async runtime is required. The
+    /// data of type `D` it resolves to is converted to a view by the
+    /// `view_creator` function. As the future is polled on the cursive thread,
+    /// it must not block. Futures which depend on a specific runtime (e.g.
+    /// timers or sockets of that runtime) must be spawned on it instead.
+    ///
+    /// The future is only polled after it has woken its waker, the loading
+    /// animation keeps running in the meantime.
+    ///
+    /// ```
+    /// use cursive::views::TextView;
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive_async_view::AsyncView;
+    ///
+    /// async fn fetch_greeting() -> Result<String, String> {
+    ///     Ok("Hello from the future!".to_string())
+    /// }
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view = AsyncView::from_future(&mut siv, fetch_greeting(), TextView::new);
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    pub fn from_future<Fut, C, D>(siv: &mut Cursive, future: Fut, mut view_creator: C) -> Self
+    where
+        E: From<String>,
+        D: 'static,
+        Fut: Future<Output = Result<D, E>> + 'static,
+        C: FnMut(D) -> T + 'static,
     {
-        match cb() {
-            AsyncState::Pending => {
-                let sink = siv.cb_sink().clone();
-                let cb = SendWrapper::new(cb);
-                thread::spawn(move || {
-                    // ensure ~60fps
-                    if let Some(duration) = FPS.checked_sub(instant.elapsed()) {
-                        thread::sleep(duration);
-                    }
-
-                    match sink.send(Box::new(move |siv| {
-                        Self::polling_cb(siv, Instant::now(), chan, end_anim, cb.take())
-                    })) {
-                        Ok(_) => {}
-                        Err(send_err) => {
-                            warn!("Could not send callback to cursive. It probably has been dropped before the asynchronous initialization of a view has been finished: {}", send_err);
-                        }
-                    }
-                });
+        let notifier = Notifier::new();
+        let mut future = LocalFuture::new(future, notifier.clone());
+
+        let mode = PollMode::notified(siv.cb_sink(), notifier);
+        Self::with_poll_mode(siv, mode, CancellationToken::new(), move || {
+            match future.poll() {
+                Poll::Ready(Ok(data)) => AsyncState::Available(view_creator(data)),
+                Poll::Ready(Err(err)) => AsyncState::Error(err),
+                Poll::Pending => AsyncState::Pending,
             }
-            AsyncState::Error(content) => {
-                // Start a thread running until the object has been dropped
-                Self::error_anim_cb(siv, end_anim);
-
-                // This may panic if the other site has been dropped Can happen
-                // if the view gets removed before the event loop has finished
-                // causing the sender to try to to communicate with a dead
-                // receiver To fix this we drop this error and warn the user
-                // that this behaviour is discouraged
-                match chan.send(AsyncState::Error(content)) {
-                    Ok(_) => {}
-                    Err(send_err) => {
-                        warn!("View has been dropped before asynchronous initialization has been finished. Check if you removed this view from Cursive: {}", send_err);
-                    }
+        })
+    }
+
+    /// Create a new `AsyncView` instance. The cursive reference is used
+    /// to control the refresh rate of the terminal when the loading animation
+    /// is running. In order to show the view, it has to be directly or indirectly
+    /// added to a cursive layer like any other view.
+    ///
+    /// The `task` is spawned on the tokio runtime behind `handle`. The data of
+    /// type `D` it resolves to is sent back to the cursive thread and converted
+    /// to a view by the `view_creator` function. A panic of the task is handled like
+    /// any other panic, see `with_panic_error`. The task is aborted once the view
+    /// does not wait for its result anymore, e.g. because the view has been dropped.
+    /// If the task is cancelled by tokio, e.g. because the runtime shuts down, the
+    /// cancel animation is shown.
+    ///
+    /// This constructor requires the `tokio` feature.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use cursive::views::TextView;
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive_async_view::AsyncView;
+    ///
+    /// let runtime = tokio::runtime::Runtime::new().unwrap();
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view: AsyncView<TextView> = AsyncView::spawn_on(runtime.handle(), &mut siv, async {
+    ///     tokio::time::sleep(Duration::from_secs(5)).await;
+    ///     Ok("Spawned on tokio!")
+    /// }, TextView::new);
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub fn spawn_on<Fut, C, D>(
+        handle: &tokio::runtime::Handle,
+        siv: &mut Cursive,
+        task: Fut,
+        mut view_creator: C,
+    ) -> Self
+    where
+        E: From<String>,
+        D: Send + 'static,
+        Fut: Future<Output = Result<D, E>> + Send + 'static,
+        C: FnMut(D) -> T + 'static,
+    {
+        let notifier = Notifier::new();
+        let mut task = LocalFuture::new(AbortOnDrop(handle.spawn(task)), notifier.clone());
+
+        let mode = PollMode::notified(siv.cb_sink(), notifier);
+        Self::with_poll_mode(siv, mode, CancellationToken::new(), move || {
+            match task.poll() {
+                Poll::Ready(Ok(Ok(data))) => AsyncState::Available(view_creator(data)),
+                Poll::Ready(Ok(Err(err))) => AsyncState::Error(err),
+                // caught by the view like a panic of the poll function
+                Poll::Ready(Err(join_err)) if join_err.is_panic() => {
+                    panic::resume_unwind(join_err.into_panic())
                 }
-                // chan dropped here, so the rx must handle disconnected
+                // e.g. the runtime has been shut down
+                Poll::Ready(Err(_)) => AsyncState::Cancelled,
+                Poll::Pending => AsyncState::Pending,
             }
-            AsyncState::Available(view) => match chan.send(AsyncState::Available(view)) {
-                Ok(_) => {}
-                Err(send_err) => {
-                    warn!("View has been dropped before asynchronous initialization has been finished. Check if you removed this view from Cursive: {}", send_err);
-                }
-            },
+        })
+    }
+
+    /// The `CancellationToken` of this view. It is cancelled once the view does
+    /// not wait for its content anymore, e.g. because it has been dropped or the
+    /// content has been loaded. Hand it to your background tasks, so they can
+    /// stop working early.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.core.token()
+    }
+
+    /// Cancel loading the content of this view. This cancels the `CancellationToken`
+    /// of this view, stops polling for the content and shows the cancel animation.
+    /// Nothing happens if the content has already been loaded or failed to load.
+    ///
+    /// Cancelling a reload which keeps the previous content on screen restores the
+    /// previous content instead.
+    pub fn cancel(&mut self) {
+        if self.core.cancel() {
+            self.error_idx = self.pos;
         }
     }
 
-    pub(crate) fn error_anim_cb(siv: &mut Cursive, chan: Receiver<()>) {
-        let sink = siv.cb_sink().clone();
-        thread::spawn(move || loop {
-            thread::sleep(Duration::from_millis(16));
-
-            match chan.try_recv() {
-                Ok(()) => break,
-                Err(_) => match sink.send(Box::new(|_| {})) {
-                    Ok(_) => {}
-                    Err(send_err) => {
-                        warn!(
-                            "Cursive has been dropped before AsyncView has been: {}",
-                            send_err
-                        );
-                    }
-                },
-            }
-        });
+    /// Load the content of this view again with a new `loader`, which creates a
+    /// fresh `ready_poll` function like the factory given to `new_retryable`. The
+    /// view moves back to the loading state, a load still in progress is cancelled.
+    /// By default, the current child view is discarded, see `with_keep_on_reload`
+    /// for keeping it on screen until the replacement arrives.
+    ///
+    /// The view is usually reloaded from a callback, where it can be found by its
+    /// name:
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive::view::Nameable;
+    /// use cursive::views::TextView;
+    /// use cursive_async_view::{AsyncView, AsyncState};
+    ///
+    /// fn load(msg: &'static str) -> impl FnMut() -> AsyncState<TextView> {
+    ///     let start = Instant::now();
+    ///     move || {
+    ///         if start.elapsed() > Duration::from_secs(2) {
+    ///             AsyncState::Available(TextView::new(msg))
+    ///         } else {
+    ///             AsyncState::Pending
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view = AsyncView::new(&mut siv, load("Initial content")).with_keep_on_reload();
+    /// siv.add_layer(async_view.with_name("panel"));
+    ///
+    /// siv.add_global_callback('r', |siv| {
+    ///     let mut panel = siv.find_name::<AsyncView<TextView>>("panel").unwrap();
+    ///     panel.reload(siv, || load("Refreshed content"));
+    /// });
+    /// // siv.run();
+    /// ```
+    ///
+    /// The `loader` replaces the loader of the view, so retrying or refreshing the
+    /// view afterwards calls `loader` again. A view created with `new_with_notifier`
+    /// keeps polling only when its notifier has been notified.
+    pub fn reload<F, P>(&mut self, siv: &mut Cursive, mut loader: F)
+    where
+        F: FnMut() -> P + 'static,
+        P: FnMut() -> AsyncState<T, E> + 'static,
+    {
+        self.core.reload(siv, move || lifecycle::poll_fn(loader()));
+        self.pos = 0;
+    }
+
+    /// Keep the current child view on screen while the view is reloaded, until the
+    /// replacement has been loaded. If loading the replacement fails, the error
+    /// animation is shown instead.
+    pub fn with_keep_on_reload(mut self) -> Self {
+        self.set_keep_on_reload(true);
+        self
+    }
+
+    /// Keep the current child view on screen and interactive while the view is
+    /// reloaded, like `with_keep_on_reload`. The top row of the child view shows
+    /// the loading animation in the meantime. If loading the replacement fails,
+    /// the previous child view stays and a badge with the error is shown in its
+    /// top row instead, until the view is reloaded again.
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive::views::TextView;
+    /// use cursive::view::Nameable;
+    /// use cursive_async_view::{AsyncView, AsyncState};
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view: AsyncView<TextView> = AsyncView::new(&mut siv, || {
+    ///     AsyncState::Available(TextView::new("Cached data"))
+    /// })
+    /// .with_stale_while_revalidate();
+    /// siv.add_layer(async_view.with_name("panel"));
+    ///
+    /// siv.add_global_callback('r', |siv| {
+    ///     // the cached data stays, but shows a badge after a second
+    ///     let start = Instant::now();
+    ///     let mut panel = siv.find_name::<AsyncView<TextView>>("panel").unwrap();
+    ///     panel.reload(siv, move || {
+    ///         move || {
+    ///             if start.elapsed() > Duration::from_secs(1) {
+    ///                 AsyncState::Error("Server unreachable".to_string())
+    ///             } else {
+    ///                 AsyncState::Pending
+    ///             }
+    ///         }
+    ///     });
+    /// });
+    /// // siv.run();
+    /// ```
+    pub fn with_stale_while_revalidate(mut self) -> Self {
+        self.set_stale_while_revalidate(true);
+        self
+    }
+
+    /// Run the loader of this view again and swap in the fresh child view once it
+    /// has been loaded. The current child view stays on screen in the meantime,
+    /// see `with_stale_while_revalidate` for showing that a refresh is running.
+    /// Nothing happens while the view is loading or has been cancelled, or if it
+    /// has not been created with a retryable constructor like `new_retryable`
+    /// or `new_with_bg_creator`, and has not been reloaded either.
+    pub fn refresh(&mut self) {
+        self.core.refresh();
+    }
+
+    /// Refresh the content of this view every `interval`, see `refresh` for
+    /// details. A view which failed to load is refreshed as well, a cancelled
+    /// view is not.
+    ///
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive::views::TextView;
+    /// use cursive_async_view::AsyncView;
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view: AsyncView<TextView> = AsyncView::new_retryable_with_bg_creator(&mut siv, |_token| {
+    ///     // query the current state of whatever is monitored
+    ///     Ok(format!("{:?}", SystemTime::now()))
+    /// }, TextView::new)
+    /// .with_refresh_interval(Duration::from_secs(5))
+    /// .with_refresh_paused_when_hidden();
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    pub fn with_refresh_interval(mut self, interval: Duration) -> Self {
+        self.set_refresh_interval(interval);
+        self
+    }
+
+    /// Skip refreshes while the view does not have the focus. Views which cannot
+    /// take the focus are never refreshed with this option.
+    pub fn with_refresh_paused_when_unfocused(mut self) -> Self {
+        self.set_refresh_paused_when_unfocused(true);
+        self
+    }
+
+    /// Skip refreshes while the view is not drawn, e.g. because it is hidden.
+    pub fn with_refresh_paused_when_hidden(mut self) -> Self {
+        self.set_refresh_paused_when_hidden(true);
+        self
+    }
+
+    /// The error of the last reload, if it failed while the previous child view has
+    /// been kept on screen by `with_stale_while_revalidate`.
+    pub fn reload_error(&self) -> Option<&E> {
+        self.core.reload_error()
+    }
+
+    /// The error the content failed to load with, while the error animation is
+    /// shown. The error keeps its type, so it can be logged or inspected further:
+    ///
+    /// ```
+    /// use std::fmt;
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive::view::Nameable;
+    /// use cursive::views::TextView;
+    /// use cursive_async_view::AsyncView;
+    ///
+    /// #[derive(Debug)]
+    /// enum FetchError {
+    ///     Offline,
+    ///     NotFound(String),
+    ///     // e.g. a panic of the loader
+    ///     Internal(String),
+    /// }
+    ///
+    /// impl fmt::Display for FetchError {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         match self {
+    ///             FetchError::Offline => write!(f, "You are offline"),
+    ///             FetchError::NotFound(what) => write!(f, "{} does not exist", what),
+    ///             FetchError::Internal(msg) => write!(f, "{}", msg),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// impl From<String> for FetchError {
+    ///     fn from(msg: String) -> Self {
+    ///         FetchError::Internal(msg)
+    ///     }
+    /// }
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view = AsyncView::new_with_bg_creator(&mut siv, || {
+    ///     Err::<String, _>(FetchError::NotFound("config.toml".to_string()))
+    /// }, TextView::new);
+    /// siv.add_layer(async_view.with_name("config"));
+    ///
+    /// siv.add_global_callback('r', |siv| {
+    ///     let view = siv.find_name::<AsyncView<TextView, FetchError>>("config").unwrap();
+    ///     if let Some(FetchError::Offline) = view.error() {
+    ///         // e.g. retry once the network is back
+    ///     }
+    /// });
+    /// // siv.run();
+    /// ```
+    pub fn error(&self) -> Option<&E> {
+        self.core.error()
+    }
+
+    /// Restart loading the content after it failed to load or has been cancelled.
+    /// Nothing happens if the view is still loading, has been loaded, or has not
+    /// been created with a retryable constructor like `new_retryable` or
+    /// `new_with_bg_creator`, and has not been reloaded either.
+    ///
+    /// A retry starts a new series of attempts of the `RetryPolicy`, if there is one.
+    pub fn retry(&mut self) {
+        if self.core.retry() {
+            self.pos = 0;
+        }
+    }
+
+    /// Automatically retry loading the content after it failed, as long as the given
+    /// policy allows another attempt. Only the last error is shown. Like `retry`, this
+    /// requires the view to be created with a retryable constructor. See the
+    /// `RetryPolicy` documentation for an example.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.set_retry_policy(policy);
+        self
+    }
+
+    /// Allow the user to retry loading the content by pressing `r` or by clicking
+    /// on the error message. See `with_retry_event` for using a different key and
+    /// `retry` for which views can be retried.
+    pub fn with_retryable(mut self) -> Self {
+        self.set_retryable(true);
+        self
+    }
+
+    /// Allow the user to retry loading the content with the given event. See
+    /// `retry` for what retrying does. A custom error view gets the event first,
+    /// the view is only retried if the error view ignores it.
+    pub fn with_retry_event<Ev: Into<Event>>(mut self, event: Ev) -> Self {
+        self.set_retry_event(event);
+        self
+    }
+
+    /// Give up loading the content once `timeout` has passed since the creation of
+    /// this view, or since it has last been reloaded, refreshed or retried. The
+    /// automatic retries of a `RetryPolicy` do not restart the timeout, the waits
+    /// between them count towards it as well. On expiry the `CancellationToken` of this view is cancelled and the
+    /// error animation is shown with the message set by `with_timeout_msg`, converted
+    /// to the error type of this view.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive::views::TextView;
+    /// use cursive_async_view::AsyncView;
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view: AsyncView<TextView> = AsyncView::new_with_cancellable_bg_creator(&mut siv, |token| {
+    ///     // takes longer than we are willing to wait
+    ///     token.wait_timeout(Duration::from_secs(60));
+    ///     Ok("Finally!")
+    /// }, TextView::new)
+    /// .with_timeout(Duration::from_secs(5))
+    /// .with_timeout_msg("The server did not answer in time")
+    /// .with_timeout_countdown();
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self
+    where
+        E: From<String>,
+    {
+        self.set_timeout(timeout);
+        self
+    }
+
+    /// Set the error message shown when loading the content timed out. Defaults to
+    /// `Loading timed out`.
+    pub fn with_timeout_msg<S: Into<String>>(mut self, msg: S) -> Self {
+        self.set_timeout_msg(msg);
+        self
+    }
+
+    /// Show the time remaining until the timeout below the loading animation.
+    pub fn with_timeout_countdown(mut self) -> Self {
+        self.set_timeout_countdown(true);
+        self
+    }
+
+    /// Create the error shown for panics of the code loading the content with
+    /// `panic_error` instead of `E::from`.
+    ///
+    /// By default, a panic in any code handed to this view, e.g. the `ready_poll`
+    /// function, a `bg_task`, a `view_creator`, a future or the factory of a
+    /// retryable view, is caught and shown as an error carrying the panic message.
+    /// The error is created by `E::from`, so it can be retried like any other error.
+    /// See `with_abort_on_panic` for letting panics abort the application instead.
+    ///
+    /// ```
+    /// use cursive::Cursive;
+    /// use cursive::view::View;
+    /// use cursive::views::TextView;
+    /// use cursive_async_view::{AsyncView, AsyncState};
+    ///
+    /// let mut siv = Cursive::default();
+    /// let mut async_view: AsyncView<TextView> = AsyncView::new(&mut siv, || {
+    ///     let records: Vec<&str> = Vec::new();
+    ///     AsyncState::Available(TextView::new(records[0]))
+    /// })
+    /// .with_panic_error(|msg| format!("Please report this bug! {}", msg));
+    ///
+    /// // the panic has been caught
+    /// async_view.required_size((40, 5).into());
+    /// assert!(async_view.error().unwrap().starts_with("Please report this bug! Loading panicked"));
+    /// ```
+    pub fn with_panic_error(mut self, panic_error: fn(String) -> E) -> Self {
+        self.set_panic_error(panic_error);
+        self
+    }
+
+    /// Let panics of the code loading the content propagate into the cursive event
+    /// loop, which aborts the application, instead of showing them as an error.
+    pub fn with_abort_on_panic(mut self) -> Self {
+        self.set_abort_on_panic(true);
+        self
+    }
+
+    /// Allow the user to cancel loading the content by pressing `Esc`. See
+    /// `with_cancel_event` for using a different key.
+    pub fn with_cancellable(mut self) -> Self {
+        self.set_cancellable(true);
+        self
+    }
+
+    /// Allow the user to cancel loading the content with the given event. See
+    /// `cancel` for what cancelling does.
+    pub fn with_cancel_event<Ev: Into<Event>>(mut self, event: Ev) -> Self {
+        self.set_cancel_event(event);
+        self
+    }
+
+    /// Set a custom cancel animation function for this view, indicating that loading the
+    /// wrapped view has been cancelled. See the `default_cancel` function reference for an
+    /// example on how to create a custom cancel animation function.
+    pub fn with_cancel_fn<F>(mut self, cancel_fn: F) -> Self
+    where
+        F: Fn(usize, usize, usize, usize) -> AnimationFrame + Send + Sync + 'static,
+    {
+        self.set_cancel_fn(cancel_fn);
+        self
     }
 
     /// Mark the maximum allowed width in characters, the loading animation may consume.
@@ -524,12 +1232,89 @@ impl<T: View> AsyncView<T> {
         // 'static, meaning it owns all values and does not reference anything
         // outside of its scope. In practice this means all animation_fn must be
         // `move |width| {...}` or fn's.
-        F: Fn(&str, usize, usize, usize, usize) -> AnimationFrame + Send + Sync + 'static,
+        F: Fn(&E, usize, usize, usize, usize) -> AnimationFrame + Send + Sync + 'static,
     {
         self.set_error_fn(error_fn);
         self
     }
 
+    /// Show the view created by `error_view` instead of the error animation, once
+    /// the content failed to load. The view is created from the error on the
+    /// cursive thread and replaced by the loading animation again on a retry.
+    ///
+    /// ```
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive::view::Nameable;
+    /// use cursive::views::{Dialog, TextView};
+    /// use cursive_async_view::AsyncView;
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view = AsyncView::new_retryable_with_bg_creator(&mut siv, |_token| {
+    ///     Err::<String, _>("Server unreachable".to_string())
+    /// }, TextView::new)
+    /// .with_error_view(|err| {
+    ///     Dialog::around(TextView::new(format!("Could not load the content:\n{}", err)))
+    ///         .title("Error")
+    ///         .button("Retry", |siv| {
+    ///             siv.call_on_name("content", |view: &mut AsyncView<TextView>| view.retry());
+    ///         })
+    ///         .button("Quit", Cursive::quit)
+    /// });
+    ///
+    /// siv.add_layer(async_view.with_name("content"));
+    /// // siv.run();
+    /// ```
+    pub fn with_error_view<F, V>(mut self, error_view: F) -> Self
+    where
+        F: Fn(&E) -> V + Send + Sync + 'static,
+        V: View,
+    {
+        self.set_error_view(error_view);
+        self
+    }
+
+    /// Show the child view created by `fallback` instead of an error, once the
+    /// content failed to load. Useful for panels which rather show cached or empty
+    /// data than an error. The error is handed to the callback set by
+    /// `with_fallback_callback`, if any.
+    ///
+    /// A fallback only replaces the final error, i.e. after the `RetryPolicy` gave
+    /// up. The previous content kept by `with_stale_while_revalidate` is preferred
+    /// over the fallback.
+    ///
+    /// ```
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive::views::TextView;
+    /// use cursive_async_view::AsyncView;
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view = AsyncView::new_with_bg_creator(&mut siv, || {
+    ///     Err::<String, _>("Server unreachable".to_string())
+    /// }, TextView::new)
+    /// .with_fallback(|_| TextView::new("No news today"))
+    /// .with_fallback_callback(|_, err| log::warn!("Could not fetch the news: {}", err));
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    pub fn with_fallback<F>(mut self, fallback: F) -> Self
+    where
+        F: Fn(&E) -> T + Send + Sync + 'static,
+    {
+        self.set_fallback(fallback);
+        self
+    }
+
+    /// Call `callback` on the cursive thread with every error replaced by the fallback.
+    /// See `with_fallback` for details.
+    pub fn with_fallback_callback<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, E) + Send + Sync + 'static,
+    {
+        self.set_fallback_callback(callback);
+        self
+    }
+
     /// Set the maximum allowed width in characters, the loading animation may consume.
     pub fn set_width(&mut self, width: usize) {
         self.width = Some(width);
@@ -561,11 +1346,184 @@ impl<T: View> AsyncView<T> {
     /// the previous error animation has already started.
     pub fn set_error_fn<F>(&mut self, error_fn: F)
     where
-        F: Fn(&str, usize, usize, usize, usize) -> AnimationFrame + Send + Sync + 'static,
+        F: Fn(&E, usize, usize, usize, usize) -> AnimationFrame + Send + Sync + 'static,
     {
         self.error_fn = Box::new(error_fn);
     }
 
+    /// Set a function creating the view shown instead of the error animation. See
+    /// `with_error_view` for details.
+    ///
+    /// This function may be set at any time, but does not replace an error view which
+    /// has already been created.
+    pub fn set_error_view<F, V>(&mut self, error_view: F)
+    where
+        F: Fn(&E) -> V + Send + Sync + 'static,
+        V: View,
+    {
+        self.core.error_view_fn = Some(Box::new(move |err| Box::new(error_view(err))));
+    }
+
+    /// Show the error animation once the content failed to load. This is the default.
+    pub fn unset_error_view(&mut self) {
+        self.core.unset_error_view();
+    }
+
+    /// Set a function creating the child view shown instead of an error. See
+    /// `with_fallback` for details.
+    pub fn set_fallback<F>(&mut self, fallback: F)
+    where
+        F: Fn(&E) -> T + Send + Sync + 'static,
+    {
+        self.core.fallback_fn = Some(Box::new(fallback));
+    }
+
+    /// Show errors instead of a fallback child view. This is the default.
+    pub fn unset_fallback(&mut self) {
+        self.core.fallback_fn = None;
+    }
+
+    /// Set the callback receiving errors replaced by the fallback. See
+    /// `with_fallback` for details.
+    pub fn set_fallback_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&mut Cursive, E) + Send + Sync + 'static,
+    {
+        self.core.fallback_callback = Some(Arc::new(callback));
+    }
+
+    /// Set whether the user may retry loading the content by pressing `r` or by
+    /// clicking on the error message. Retrying is disabled by default.
+    pub fn set_retryable(&mut self, retryable: bool) {
+        self.core.set_retryable(retryable);
+    }
+
+    /// Set the event which retries loading the content, while the error or cancel
+    /// animation is shown. The view takes the focus to receive the event.
+    pub fn set_retry_event<Ev: Into<Event>>(&mut self, event: Ev) {
+        self.core.retry_event = Some(event.into());
+    }
+
+    /// Set whether the current child view stays on screen while the view is
+    /// reloaded. The child view is discarded by default.
+    pub fn set_keep_on_reload(&mut self, keep: bool) {
+        self.core.keep_on_reload = keep;
+    }
+
+    /// Set whether the current child view stays on screen with an overlay while the
+    /// view is reloaded. See `with_stale_while_revalidate` for details. Enabling this
+    /// also keeps the child view on reload.
+    pub fn set_stale_while_revalidate(&mut self, revalidate: bool) {
+        self.core.set_stale_while_revalidate(revalidate);
+    }
+
+    /// Set the interval in which the content of this view is refreshed. See
+    /// `with_refresh_interval` for details.
+    pub fn set_refresh_interval(&mut self, interval: Duration) {
+        self.unset_refresh_interval();
+        self.refresh_interval = Some(interval);
+        notify::refresh_timer(
+            &self.core.sink,
+            interval,
+            Arc::clone(&self.refresh_due),
+            self.refresh_timer.clone(),
+        );
+    }
+
+    /// Do not refresh the content of this view periodically. This is the default.
+    pub fn unset_refresh_interval(&mut self) {
+        self.refresh_timer.cancel();
+        self.refresh_timer = CancellationToken::new();
+        self.refresh_interval = None;
+    }
+
+    /// Set whether refreshes are skipped while the view does not have the focus.
+    /// Refreshes are not skipped by default.
+    pub fn set_refresh_paused_when_unfocused(&mut self, paused: bool) {
+        self.refresh_unfocused = !paused;
+    }
+
+    /// Set whether refreshes are skipped while the view is not drawn. Refreshes
+    /// are not skipped by default.
+    pub fn set_refresh_paused_when_hidden(&mut self, paused: bool) {
+        self.refresh_hidden = !paused;
+    }
+
+    /// Set the policy to automatically retry loading the content after it failed.
+    /// See `with_retry_policy` for details.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.core.retry_policy = Some(policy);
+    }
+
+    /// Do not retry loading the content automatically. This is the default.
+    pub fn unset_retry_policy(&mut self) {
+        self.core.retry_policy = None;
+    }
+
+    /// Set the duration after which loading the content is given up, measured from
+    /// the creation, the last reload, refresh or retry of this view. See
+    /// `with_timeout` for details.
+    pub fn set_timeout(&mut self, timeout: Duration)
+    where
+        E: From<String>,
+    {
+        self.core.set_timeout(timeout, E::from);
+    }
+
+    /// Wait for the content without a timeout. This is the default.
+    pub fn unset_timeout(&mut self) {
+        self.core.timeout = None;
+    }
+
+    /// Set the error message shown when loading the content timed out.
+    pub fn set_timeout_msg<S: Into<String>>(&mut self, msg: S) {
+        self.core.timeout_msg = msg.into();
+    }
+
+    /// Set whether the time remaining until the timeout is shown below the loading
+    /// animation. The countdown is hidden by default.
+    pub fn set_timeout_countdown(&mut self, countdown: bool) {
+        self.core.timeout_countdown = countdown;
+    }
+
+    /// Set the function creating the error shown for panics of the code loading the
+    /// content. See `with_panic_error` for details.
+    pub fn set_panic_error(&mut self, panic_error: fn(String) -> E) {
+        self.core.panic_error = panic_error;
+    }
+
+    /// Set whether panics of the code loading the content propagate into the
+    /// cursive event loop instead of being shown as an error. See
+    /// `with_abort_on_panic` for details.
+    pub fn set_abort_on_panic(&mut self, abort: bool) {
+        self.core.abort_on_panic = abort;
+    }
+
+    /// Set whether the user may cancel loading the content by pressing `Esc`.
+    /// Cancelling is disabled by default.
+    pub fn set_cancellable(&mut self, cancellable: bool) {
+        self.core.set_cancellable(cancellable);
+    }
+
+    /// Set the event which cancels loading the content, while the loading
+    /// animation is shown. The view takes the focus to receive the event.
+    pub fn set_cancel_event<Ev: Into<Event>>(&mut self, event: Ev) {
+        self.core.cancel_event = Some(event.into());
+    }
+
+    /// Set a custom cancel animation function for this view, indicating that loading the
+    /// wrapped view has been cancelled. See the `default_cancel` function reference for an
+    /// example on how to create a custom cancel animation function.
+    ///
+    /// This function may be set at any time. The cancel animation can be changed even if
+    /// the previous cancel animation has already started.
+    pub fn set_cancel_fn<F>(&mut self, cancel_fn: F)
+    where
+        F: Fn(usize, usize, usize, usize) -> AnimationFrame + Send + Sync + 'static,
+    {
+        self.cancel_fn = Box::new(cancel_fn);
+    }
+
     /// Make the loading animation inherit its width from the parent view. This is the default.
     pub fn inherit_width(&mut self) {
         self.width = None;
@@ -575,75 +1533,142 @@ impl<T: View> AsyncView<T> {
     pub fn inherit_height(&mut self) {
         self.height = None;
     }
+
+    /// Refresh the content if the refresh timer ticked since the last check and
+    /// refreshing is not paused.
+    fn check_refresh(&mut self) {
+        if !self.refresh_due.swap(false, Ordering::AcqRel) {
+            return;
+        }
+
+        // whether the view has been drawn since the last tick
+        let drawn = self.drawn.swap(false, Ordering::AcqRel);
+        if (self.refresh_hidden || drawn) && (self.refresh_unfocused || self.focused) {
+            self.refresh();
+        }
+    }
 }
 
-impl<T: View> Drop for AsyncView<T> {
+impl<T: View, E> Drop for AsyncView<T, E> {
     fn drop(&mut self) {
-        match self.error_sender.send(()) {
-            Ok(_) => {}
-            Err(send_err) => warn!(
-                "Refreshing thread has been dropped before view has, this has no impact on your code and is a bug: {}",
-                send_err
-            ),
-        }
+        self.refresh_timer.cancel();
+    }
+}
+
+// run `bg_task` on its own thread, its result or panic is sent to the returned receiver
+fn spawn_bg_task<R, F>(bg_task: F) -> Receiver<thread::Result<R>>
+where
+    R: Send + 'static,
+    F: FnOnce() -> R + Send + 'static,
+{
+    let (tx, rx) = channel::unbounded();
+
+    thread::Builder::new()
+        .name("cursive-async-view::bg_task".into())
+        .spawn(move || {
+            // the view may have been dropped already
+            tx.send(unwind::catch(bg_task)).ok();
+        })
+        .unwrap();
+
+    rx
+}
+
+// a `ready_poll` function creating the view from the result of a bg task
+fn bg_ready_poll<T, E, D, C>(
+    rx: Receiver<thread::Result<Result<D, E>>>,
+    mut view_creator: C,
+) -> impl FnMut() -> AsyncState<T, E>
+where
+    T: View,
+    E: From<String>,
+    C: FnMut(D) -> T,
+{
+    move || match rx.try_recv() {
+        Ok(Ok(Ok(data))) => AsyncState::Available(view_creator(data)),
+        Ok(Ok(Err(err))) => AsyncState::Error(err),
+        // resumed on the cursive thread, where the view handles it
+        Ok(Err(payload)) => panic::resume_unwind(payload),
+        Err(TryRecvError::Empty) => AsyncState::Pending,
+        Err(TryRecvError::Disconnected) => AsyncState::Error(E::from(
+            "Internal error: bg_task disconnected unexpectedly!".to_string(),
+        )),
     }
 }
 
-impl<T: View + Sized> View for AsyncView<T> {
+impl<T: View + Sized, E: Display + Send + Sync + 'static> View for AsyncView<T, E> {
     fn draw(&self, printer: &Printer) {
-        match self.view {
-            AsyncState::Available(ref view) => view.draw(printer),
-            _ => self.loading.draw(printer),
-        }
+        self.drawn.store(true, Ordering::Release);
+        self.core.draw(printer);
     }
 
     fn layout(&mut self, vec: Vec2) {
-        match self.view {
-            AsyncState::Available(ref mut view) => view.layout(vec),
-            _ => self.loading.layout(vec),
-        }
+        self.check_refresh();
+        self.core.layout(vec);
     }
 
     fn needs_relayout(&self) -> bool {
-        match self.view {
-            AsyncState::Available(ref view) => view.needs_relayout(),
-            _ => true,
-        }
+        self.core.needs_relayout()
     }
 
     fn required_size(&mut self, constraint: Vec2) -> Vec2 {
-        match self.rx.try_recv() {
-            Ok(view) => {
-                if let AsyncState::Error(_) = view {
-                    self.error_idx = self.pos;
-                }
+        if let Change::Failed = self.core.update() {
+            self.error_idx = self.pos;
+        }
 
-                self.view = view;
-            }
-            Err(TryRecvError::Empty) => {
-                // if empty, try next tick
-            }
-            Err(TryRecvError::Disconnected) => {
-                // if disconnected, view is loaded or error message is displayed
-            }
+        if let Some(view) = self.core.error_view_mut() {
+            return view.required_size(constraint);
         }
 
-        match self.view {
-            AsyncState::Available(ref mut view) => view.required_size(constraint),
-            AsyncState::Error(ref msg) => {
+        match self.core.phase {
+            Phase::Available(ref mut view) => view.required_size(constraint),
+            Phase::Error(ref err) => {
                 let width = self.width.unwrap_or(constraint.x);
                 let height = self.height.unwrap_or(constraint.y);
 
                 let AnimationFrame {
                     content,
                     next_frame_idx,
-                } = (self.error_fn)(msg, width, height, self.error_idx, self.pos);
-                self.loading.set_content(content);
+                } = (self.error_fn)(err, width, height, self.error_idx, self.pos);
+                let details = self
+                    .core
+                    .details
+                    .get_or_insert_with(|| ErrorDetails::new(err.to_string()));
+                details.set_frame(content, width, next_frame_idx == self.pos);
                 self.pos = next_frame_idx;
 
-                self.loading.required_size(constraint)
+                // the details scroll within the size set by `with_width` and `with_height`
+                details.required_size(Vec2::min(constraint, (width, height)))
             }
-            AsyncState::Pending => {
+            Phase::Cancelled => {
+                let width = self.width.unwrap_or(constraint.x);
+                let height = self.height.unwrap_or(constraint.y);
+
+                let AnimationFrame {
+                    content,
+                    next_frame_idx,
+                } = (self.cancel_fn)(width, height, self.error_idx, self.pos);
+                self.core.loading.set_content(content);
+                self.pos = next_frame_idx;
+
+                self.core.loading.required_size(constraint)
+            }
+            Phase::Pending if self.core.stale.is_some() => {
+                if self.core.revalidate {
+                    let width = self.width.unwrap_or(constraint.x);
+
+                    let AnimationFrame {
+                        content,
+                        next_frame_idx,
+                    } = (self.animation_fn)(width, 1, self.pos);
+                    self.core.overlay = content;
+                    self.pos = next_frame_idx;
+                }
+
+                // the previous content stays until the new one has been loaded
+                self.core.stale.as_mut().unwrap().required_size(constraint)
+            }
+            Phase::Pending => {
                 let width = self.width.unwrap_or(constraint.x);
                 let height = self.height.unwrap_or(constraint.y);
 
@@ -651,45 +1676,50 @@ impl<T: View + Sized> View for AsyncView<T> {
                     content,
                     next_frame_idx,
                 } = (self.animation_fn)(width, height, self.pos);
-                self.loading.set_content(content);
+                self.core.loading.set_content(content);
+                if let Some(line) = self.core.status_line(width) {
+                    self.core.loading.append(line);
+                }
                 self.pos = next_frame_idx;
 
-                self.loading.required_size(constraint)
+                self.core.loading.required_size(constraint)
             }
         }
     }
 
     fn on_event(&mut self, ev: Event) -> EventResult {
-        match self.view {
-            AsyncState::Available(ref mut view) => view.on_event(ev),
-            _ => EventResult::Ignored,
+        if let Event::FocusLost = ev {
+            self.focused = false;
+        }
+
+        match self.core.on_event(ev) {
+            Handled::Event(result) => result,
+            Handled::Retry => {
+                self.retry();
+                EventResult::Consumed(None)
+            }
+            Handled::Cancel => {
+                self.cancel();
+                EventResult::Consumed(None)
+            }
         }
     }
 
     fn call_on_any<'a>(&mut self, sel: &Selector, cb: AnyCb<'a>) {
-        if let AsyncState::Available(ref mut view) = self.view {
-            view.call_on_any(sel, cb)
-        }
+        self.core.call_on_any(sel, cb);
     }
 
     fn focus_view(&mut self, sel: &Selector) -> Result<EventResult, ViewNotFound> {
-        match self.view {
-            AsyncState::Available(ref mut view) => view.focus_view(sel),
-            _ => Err(ViewNotFound),
-        }
+        self.core.focus_view(sel)
     }
 
     fn take_focus(&mut self, source: Direction) -> Result<EventResult, CannotFocus> {
-        match self.view {
-            AsyncState::Available(ref mut view) => view.take_focus(source),
-            _ => Err(CannotFocus),
-        }
+        let result = self.core.take_focus(source);
+        self.focused = result.is_ok();
+        result
     }
 
     fn important_area(&self, view_size: Vec2) -> Rect {
-        match self.view {
-            AsyncState::Available(ref view) => view.important_area(view_size),
-            _ => self.loading.important_area(view_size),
-        }
+        self.core.important_area(view_size)
     }
 }
@@ -17,7 +17,7 @@
 //!
 //! let mut siv = Cursive::default();
 //! let instant = Instant::now();
-//! let async_view = AsyncView::new(&mut siv, move || {
+//! let async_view: AsyncView<TextView> = AsyncView::new(&mut siv, move || {
 //!     if instant.elapsed() > Duration::from_secs(10) {
 //!         AsyncState::Available(
 //!             TextView::new("Yay!\n\nThe content has loaded!")
@@ -47,7 +47,7 @@
 //! use cursive_async_view::AsyncView;
 //!
 //! let mut siv = Cursive::default();
-//! let async_view = AsyncView::new_with_bg_creator(&mut siv, move || {
+//! let async_view: AsyncView<TextView> = AsyncView::new_with_bg_creator(&mut siv, move || {
 //!     // this function is executed in a background thread, so we can block
 //!     // here as long as we like
 //!     thread::sleep(Duration::from_secs(10));
@@ -64,6 +64,29 @@
 //! explanation or to the `bg_task` example located in the source code
 //! repository.
 //!
+//! If your data is produced by a `std::future::Future`, e.g. an `async fn`, you
+//! may use the `from_future` constructor instead. The future is driven by a small
+//! built-in executor, so no particular async runtime is required.
+//!
+//! ```
+//! use cursive::views::TextView;
+//! use cursive::{Cursive, CursiveExt};
+//! use cursive_async_view::AsyncView;
+//!
+//! async fn load() -> Result<String, String> {
+//!     Ok("Loaded by a future!".to_string())
+//! }
+//!
+//! let mut siv = Cursive::default();
+//! let async_view = AsyncView::from_future(&mut siv, load(), TextView::new);
+//!
+//! siv.add_layer(async_view);
+//! // siv.run();
+//! ```
+//!
+//! Refer to the `future` example located in the source code repository for a
+//! future which is woken from a different thread.
+//!
 //! # Asynchronous view loading with a progress bar
 //!
 //! If you have information about the progress a long taking view creation has made,
@@ -76,7 +99,7 @@
 //!
 //! let mut siv = Cursive::default();
 //! let start = std::time::Instant::now();
-//! let async_view = AsyncProgressView::new(&mut siv, move || {
+//! let async_view: AsyncProgressView<TextView> = AsyncProgressView::new(&mut siv, move || {
 //!     if start.elapsed().as_secs() < 3 {
 //!         AsyncProgressState::Pending(start.elapsed().as_secs() as f32 / 3f32)
 //!     } else {
@@ -88,14 +111,31 @@
 //! // siv.run();
 //! ```
 
+mod cancel;
+mod details;
+mod estimate;
+mod executor;
 mod infinite;
+mod lifecycle;
+mod notify;
 mod progress;
+mod reporter;
+mod retry;
+mod status;
+mod unwind;
 mod utils;
 
-pub use infinite::{default_animation, default_error, AnimationFrame, AsyncState, AsyncView};
+pub use cancel::CancellationToken;
+pub use infinite::{
+    default_animation, default_cancel, default_error, AnimationFrame, AsyncState, AsyncView,
+};
+pub use notify::Notifier;
 pub use progress::{
-    default_progress, default_progress_error, AnimationProgressFrame, AsyncProgressState,
-    AsyncProgressView,
+    default_progress, default_progress_cancel, default_progress_error, AnimationProgressFrame,
+    AsyncProgressState, AsyncProgressView, ProgressEvent,
 };
+pub use reporter::ProgressReporter;
+pub use retry::RetryPolicy;
+pub use status::{Progress, Unit};
 
 doc_comment::doctest!("../README.md");
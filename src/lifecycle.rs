@@ -0,0 +1,864 @@
+use std::fmt::Display;
+use std::mem;
+use std::panic;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam::channel::{self, Receiver, Sender, TryRecvError};
+use cursive_core::direction::Direction;
+use cursive_core::event::{AnyCb, Event, EventResult, Key, MouseButton, MouseEvent};
+use cursive_core::utils::markup::StyledString;
+use cursive_core::view::{CannotFocus, Selector, View, ViewNotFound};
+use cursive_core::views::TextView;
+use cursive_core::{CbSink, Cursive, Printer, Rect, Vec2};
+use log::warn;
+use send_wrapper::SendWrapper;
+
+use crate::cancel::CancellationToken;
+use crate::details::ErrorDetails;
+use crate::notify::{self, Notifier, PollMode};
+use crate::retry::RetryPolicy;
+use crate::status::Progress;
+use crate::unwind;
+use crate::utils;
+
+/// The state of the content of an async view.
+pub(crate) enum Phase<T, E> {
+    /// The content is being loaded, or the next attempt is waited for.
+    Pending,
+    /// The content has been loaded.
+    Available(T),
+    /// Loading the content failed.
+    Error(E),
+    /// Loading the content has been cancelled.
+    Cancelled,
+}
+
+/// The result of a single call of the poll function of an async view. The
+/// `AsyncState` and `AsyncProgressState` returned by user code are converted
+/// into it.
+pub(crate) enum Polled<T, E> {
+    /// The content is not available yet, optionally with the progress made.
+    Pending(Option<Progress>),
+    Available(T),
+    Error(E),
+    Cancelled,
+}
+
+/// What happened to the load during `Lifecycle::update`, which the view has to
+/// react to with its animations.
+pub(crate) enum Change {
+    None,
+    /// The poll function reported new progress.
+    Progress(Progress),
+    /// The next attempt of the retry policy has been started.
+    Restarted,
+    /// Loading failed or timed out, the error animation starts.
+    Failed,
+}
+
+/// How `Lifecycle::on_event` handled an event.
+pub(crate) enum Handled {
+    Event(EventResult),
+    /// The event asks for a retry, see `AsyncView::retry`.
+    Retry,
+    /// The event asks for cancelling, see `AsyncView::cancel`.
+    Cancel,
+}
+
+pub(crate) type PollFn<T, E> = Box<dyn FnMut() -> Polled<T, E> + 'static>;
+type StateSender<T, E> = SendWrapper<Sender<thread::Result<Polled<T, E>>>>;
+type LoaderFn<T, E> =
+    Box<dyn FnMut(&CbSink, &CancellationToken) -> (PollMode, PollFn<T, E>) + 'static>;
+type ErrorViewFn<E> = Box<dyn Fn(&E) -> Box<dyn View> + Send + Sync + 'static>;
+type FallbackFn<T, E> = Box<dyn Fn(&E) -> T + Send + Sync + 'static>;
+type FallbackCallback<E> = Arc<dyn Fn(&mut Cursive, E) + Send + Sync + 'static>;
+
+/// Box the poll function `poll` of a view, converting the states it returns.
+pub(crate) fn poll_fn<T, E, S, F>(mut poll: F) -> PollFn<T, E>
+where
+    S: Into<Polled<T, E>>,
+    F: FnMut() -> S + 'static,
+{
+    Box::new(move || poll().into())
+}
+
+/// The loading lifecycle shared by `AsyncView` and `AsyncProgressView`. It polls
+/// the content, retries and times out loads, keeps the previous content during a
+/// reload and creates the error view or fallback once loading failed. The views
+/// draw their animations on top of it.
+pub(crate) struct Lifecycle<T: View, E> {
+    pub(crate) phase: Phase<T, E>,
+    pub(crate) loading: TextView,
+    pub(crate) overlay: StyledString,
+    pub(crate) details: Option<ErrorDetails>,
+    pub(crate) error_view_fn: Option<ErrorViewFn<E>>,
+    error_view: Option<Box<dyn View>>,
+    pub(crate) fallback_fn: Option<FallbackFn<T, E>>,
+    pub(crate) fallback_callback: Option<FallbackCallback<E>>,
+    rx: Receiver<thread::Result<Polled<T, E>>>,
+    stop_tx: Sender<()>,
+    stop_rx: Receiver<()>,
+    token: CancellationToken,
+    pub(crate) cancel_event: Option<Event>,
+    pub(crate) sink: CbSink,
+    started: Instant,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) timeout_msg: String,
+    timeout_error: Option<fn(String) -> E>,
+    pub(crate) timeout_countdown: bool,
+    pub(crate) panic_error: fn(String) -> E,
+    pub(crate) abort_on_panic: bool,
+    // the loader is only ever called on the cursive thread
+    loader: Option<SendWrapper<LoaderFn<T, E>>>,
+    // the notifier given to `new_with_notifier`, reloads are polled when it is notified
+    pub(crate) notifier: Option<Notifier>,
+    pub(crate) retry_event: Option<Event>,
+    retry_on_click: bool,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+    attempt: usize,
+    retry_at: Option<Instant>,
+    size: Vec2,
+    pub(crate) keep_on_reload: bool,
+    pub(crate) stale: Option<T>,
+    pub(crate) revalidate: bool,
+    reload_error: Option<E>,
+}
+
+impl<T: View, E: Display + Send + Sync + 'static> Lifecycle<T, E> {
+    /// Start loading the content by calling `poll` once, further polls are
+    /// scheduled according to `mode`.
+    pub(crate) fn new(
+        siv: &mut Cursive,
+        mode: PollMode,
+        token: CancellationToken,
+        poll: PollFn<T, E>,
+    ) -> Self
+    where
+        E: From<String>,
+    {
+        // create communication channel between cursive event loop and
+        // this views layout code
+        let (tx, rx) = channel::unbounded();
+        let (stop_tx, stop_rx) = channel::bounded(1);
+
+        let instant = Instant::now();
+        Self::polling_cb(
+            siv,
+            instant,
+            SendWrapper::new(tx),
+            stop_rx.clone(),
+            token.clone(),
+            mode,
+            poll,
+        );
+
+        Self {
+            phase: Phase::Pending,
+            loading: TextView::new(""),
+            overlay: StyledString::default(),
+            details: None,
+            error_view_fn: None,
+            error_view: None,
+            fallback_fn: None,
+            fallback_callback: None,
+            rx,
+            stop_tx,
+            stop_rx,
+            token,
+            cancel_event: None,
+            sink: siv.cb_sink().clone(),
+            started: instant,
+            timeout: None,
+            timeout_msg: "Loading timed out".to_string(),
+            timeout_error: None,
+            timeout_countdown: false,
+            panic_error: E::from,
+            abort_on_panic: false,
+            loader: None,
+            notifier: None,
+            retry_event: None,
+            retry_on_click: false,
+            retry_policy: None,
+            attempt: 1,
+            retry_at: None,
+            size: Vec2::zero(),
+            keep_on_reload: false,
+            stale: None,
+            revalidate: false,
+            reload_error: None,
+        }
+    }
+
+    /// Start loading the content with the poll function created by `loader`. The
+    /// loader is called again for every retry.
+    pub(crate) fn with_loader<L>(siv: &mut Cursive, mut loader: L) -> Self
+    where
+        E: From<String>,
+        L: FnMut(&CbSink, &CancellationToken) -> (PollMode, PollFn<T, E>) + 'static,
+    {
+        let token = CancellationToken::new();
+        let (mode, poll) = Self::call_loader(&mut loader, siv.cb_sink(), &token);
+
+        let mut lifecycle = Self::new(siv, mode, token, poll);
+        lifecycle.loader = Some(SendWrapper::new(Box::new(loader)));
+        lifecycle
+    }
+
+    fn polling_cb(
+        siv: &mut Cursive,
+        instant: Instant,
+        chan: StateSender<T, E>,
+        end_anim: Receiver<()>,
+        token: CancellationToken,
+        mode: PollMode,
+        mut poll: PollFn<T, E>,
+    ) {
+        if token.is_cancelled() {
+            // nobody is interested in the result anymore
+            return;
+        }
+
+        let result = unwind::catch(&mut poll);
+        if !matches!(result, Ok(Polled::Pending(_))) {
+            // the producer is done, whatever it still does is useless now
+            token.cancel();
+        }
+
+        match result {
+            Ok(Polled::Pending(progress)) => {
+                if let Some(progress) = progress {
+                    match chan.send(Ok(Polled::Pending(Some(progress)))) {
+                        Ok(_) => {},
+                        Err(send_err) => warn!("Could not send progress to AsyncProgressView. It probably has been dropped before the asynchronous initialization of a view has been finished: {}", send_err),
+                    }
+                }
+
+                let sink = siv.cb_sink().clone();
+                let waker = mode.waker();
+                notify::schedule_poll(&sink, instant, waker, &token.clone(), move |siv| {
+                    Self::polling_cb(siv, Instant::now(), chan, end_anim, token, mode, poll)
+                });
+            }
+            Ok(Polled::Available(view)) => match chan.send(Ok(Polled::Available(view))) {
+                Ok(_) => {}
+                Err(send_err) => {
+                    warn!("View has been dropped before asynchronous initialization has been finished. Check if you removed this view from Cursive: {}", send_err);
+                }
+            },
+            result => {
+                // Start a thread running until the object has been dropped
+                notify::animation_ticker(siv.cb_sink(), end_anim);
+
+                // This may panic if the other site has been dropped Can happen
+                // if the view gets removed before the event loop has finished
+                // causing the sender to try to to communicate with a dead
+                // receiver To fix this we drop this error and warn the user
+                // that this behaviour is discouraged
+                match chan.send(result) {
+                    Ok(_) => {}
+                    Err(send_err) => {
+                        warn!("View has been dropped before asynchronous initialization has been finished. Check if you removed this view from Cursive: {}", send_err);
+                    }
+                }
+                // chan dropped here, so the rx must handle disconnected
+            }
+        }
+    }
+
+    pub(crate) fn token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+
+    pub(crate) fn is_pending(&self) -> bool {
+        matches!(self.phase, Phase::Pending)
+    }
+
+    pub(crate) fn error(&self) -> Option<&E> {
+        match self.phase {
+            Phase::Error(ref err) => Some(err),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn reload_error(&self) -> Option<&E> {
+        self.reload_error.as_ref()
+    }
+
+    pub(crate) fn set_retryable(&mut self, retryable: bool) {
+        self.retry_event = if retryable {
+            Some(Event::Char('r'))
+        } else {
+            None
+        };
+        self.retry_on_click = retryable;
+    }
+
+    pub(crate) fn set_cancellable(&mut self, cancellable: bool) {
+        self.cancel_event = if cancellable {
+            Some(Event::Key(Key::Esc))
+        } else {
+            None
+        };
+    }
+
+    pub(crate) fn set_stale_while_revalidate(&mut self, revalidate: bool) {
+        self.revalidate = revalidate;
+        if revalidate {
+            self.keep_on_reload = true;
+        }
+    }
+
+    pub(crate) fn set_timeout(&mut self, timeout: Duration, into_error: fn(String) -> E) {
+        self.timeout = Some(timeout);
+        self.timeout_error = Some(into_error);
+    }
+
+    pub(crate) fn unset_error_view(&mut self) {
+        self.error_view_fn = None;
+        self.error_view = None;
+    }
+
+    /// Cancel the load, see `AsyncView::cancel`. Returns whether the cancel
+    /// animation is shown now.
+    pub(crate) fn cancel(&mut self) -> bool {
+        if !self.is_pending() {
+            return false;
+        }
+
+        self.token.cancel();
+        self.retry_at = None;
+
+        if self.restore_stale(None) {
+            return false;
+        }
+
+        self.phase = Phase::Cancelled;
+
+        // the polling has stopped, keep the cancel animation running
+        notify::animation_ticker(&self.sink, self.stop_rx.clone());
+        true
+    }
+
+    /// Load the content again with the poll function created by `loader`, which
+    /// replaces the loader of the view for retries and refreshes. The poll mode of
+    /// the view is kept.
+    pub(crate) fn reload<L>(&mut self, siv: &mut Cursive, mut loader: L)
+    where
+        L: FnMut() -> PollFn<T, E> + 'static,
+    {
+        let notifier = self.notifier.clone();
+        let mut loader = move |sink: &CbSink, _: &CancellationToken| {
+            let mode = match notifier {
+                Some(ref notifier) => PollMode::notified(sink, notifier.clone()),
+                None => PollMode::Fps,
+            };
+            (mode, loader())
+        };
+
+        let token = CancellationToken::new();
+        let (mode, poll) = Self::call_loader(&mut loader, siv.cb_sink(), &token);
+        self.loader = Some(SendWrapper::new(Box::new(loader)));
+
+        let (chan, end_anim) = self.reset(token.clone());
+        self.attempt = 1;
+        self.started = Instant::now();
+        Self::polling_cb(siv, Instant::now(), chan, end_anim, token, mode, poll);
+    }
+
+    /// Run the loader again while the current content stays, see `AsyncView::refresh`.
+    pub(crate) fn refresh(&mut self) {
+        if self.loader.is_none() || !matches!(self.phase, Phase::Available(_) | Phase::Error(_)) {
+            return;
+        }
+
+        if let Phase::Available(view) = mem::replace(&mut self.phase, Phase::Pending) {
+            self.stale = Some(view);
+        }
+
+        self.attempt = 1;
+        self.started = Instant::now();
+        self.load();
+    }
+
+    /// Restart loading after it failed or has been cancelled, see `AsyncView::retry`.
+    /// Returns whether loading has been restarted.
+    pub(crate) fn retry(&mut self) -> bool {
+        if !self.is_retryable() {
+            return false;
+        }
+
+        self.attempt = 1;
+        self.started = Instant::now();
+        self.load();
+        true
+    }
+
+    /// Receive the latest state of the load and check for due retries, the timeout,
+    /// the fallback and the error view. Called on every layout of the view.
+    pub(crate) fn update(&mut self) -> Change {
+        let mut change = Change::None;
+
+        // a cancelled view ignores content which arrived in the meantime
+        if self.is_pending() {
+            match self.rx.try_recv().map(|result| self.recover(result)) {
+                Ok(Polled::Pending(Some(progress))) => change = Change::Progress(progress),
+                Ok(Polled::Pending(None)) => {}
+                Ok(Polled::Error(_)) if self.schedule_retry() => {
+                    // keep showing the loading animation until the next attempt
+                }
+                Ok(Polled::Error(err)) if self.revalidate && self.stale.is_some() => {
+                    self.restore_stale(Some(err));
+                }
+                Ok(Polled::Cancelled) if self.stale.is_some() => {
+                    self.restore_stale(None);
+                }
+                Ok(Polled::Available(view)) => {
+                    self.phase = Phase::Available(view);
+                    self.stale = None;
+                }
+                Ok(Polled::Error(err)) => {
+                    self.phase = Phase::Error(err);
+                    self.stale = None;
+                    change = Change::Failed;
+                }
+                Ok(Polled::Cancelled) => {
+                    self.phase = Phase::Cancelled;
+                    change = Change::Failed;
+                }
+                Err(TryRecvError::Empty) => {
+                    // if empty, try next tick
+                }
+                Err(TryRecvError::Disconnected) => {
+                    // if disconnected, view is loaded or error message is displayed
+                }
+            }
+        }
+
+        if self.check_retry() {
+            change = Change::Restarted;
+        }
+        if self.check_timeout() {
+            change = Change::Failed;
+        }
+        self.check_fallback();
+        self.check_error_view();
+
+        change
+    }
+
+    /// The line shown below the loading animation, counting down to the next
+    /// attempt or the timeout.
+    pub(crate) fn status_line(&self, width: usize) -> Option<String> {
+        if let (Some(retry_at), Some(policy)) = (self.retry_at, &self.retry_policy) {
+            let remaining = retry_at.saturating_duration_since(Instant::now());
+            Some(utils::retry_line(
+                remaining,
+                self.attempt + 1,
+                policy.max_attempts(),
+                width,
+            ))
+        } else if let (true, Some(remaining)) = (self.timeout_countdown, self.remaining()) {
+            Some(utils::countdown_line(remaining, width))
+        } else {
+            None
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        self.loader.is_some() && matches!(self.phase, Phase::Error(_) | Phase::Cancelled)
+    }
+
+    fn is_retry_event(&self, ev: &Event) -> bool {
+        if self.retry_event.as_ref() == Some(ev) {
+            return true;
+        }
+
+        match *ev {
+            Event::Mouse {
+                offset,
+                position,
+                event: MouseEvent::Release(MouseButton::Left),
+            } => {
+                // clicks belong to a custom error view or the expanded details
+                self.retry_on_click
+                    && self.error_view.is_none()
+                    && !self.details.as_ref().is_some_and(ErrorDetails::is_expanded)
+                    && position.fits_in_rect(offset, self.size)
+            }
+            _ => false,
+        }
+    }
+
+    fn load(&mut self) {
+        if let Some(ref mut loader) = self.loader {
+            let token = CancellationToken::new();
+            let (mode, poll) = Self::call_loader(&mut **loader, &self.sink, &token);
+            self.restart(mode, token, poll);
+        }
+    }
+
+    /// Create the poll function of the next load. A panic of the loader is
+    /// handled like a panic of the poll function.
+    fn call_loader<L>(
+        loader: &mut L,
+        sink: &CbSink,
+        token: &CancellationToken,
+    ) -> (PollMode, PollFn<T, E>)
+    where
+        L: FnMut(&CbSink, &CancellationToken) -> (PollMode, PollFn<T, E>) + ?Sized,
+    {
+        unwind::catch(|| loader(sink, token))
+            .unwrap_or_else(|payload| (PollMode::Fps, Box::new(unwind::resume_later(payload))))
+    }
+
+    /// Schedule the next attempt if the retry policy allows it. Returns whether
+    /// an attempt has been scheduled.
+    fn schedule_retry(&mut self) -> bool {
+        let delay = match (&self.loader, &self.retry_policy) {
+            (Some(_), Some(policy)) => policy.delay(self.attempt),
+            _ => None,
+        };
+
+        self.retry_at = delay.and_then(|delay| Instant::now().checked_add(delay));
+        self.retry_at.is_some()
+    }
+
+    /// Start the next attempt once it is due. Returns whether it has been started.
+    fn check_retry(&mut self) -> bool {
+        match (&self.phase, self.retry_at) {
+            (Phase::Pending, Some(retry_at)) if retry_at <= Instant::now() => {
+                self.attempt += 1;
+                self.load();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn restart(&mut self, mode: PollMode, token: CancellationToken, poll: PollFn<T, E>) {
+        let (chan, end_anim) = self.reset(token.clone());
+        let start = SendWrapper::new(move |siv: &mut Cursive| {
+            Self::polling_cb(siv, Instant::now(), chan, end_anim, token, mode, poll)
+        });
+
+        match self.sink.send(Box::new(move |siv| (start.take())(siv))) {
+            Ok(_) => {}
+            Err(send_err) => {
+                warn!("Could not send callback to cursive. It probably has been dropped before the asynchronous initialization of a view has been finished: {}", send_err);
+            }
+        }
+    }
+
+    /// Move back to the loading state for a new load using `token`. Returns the
+    /// channels to hand to `polling_cb`.
+    fn reset(&mut self, token: CancellationToken) -> (StateSender<T, E>, Receiver<()>) {
+        // stop whatever the previous load is still doing
+        self.token.cancel();
+
+        let (tx, rx) = channel::unbounded();
+        // replacing the sender stops the tickers of the previous load
+        let (stop_tx, stop_rx) = channel::bounded(1);
+
+        if let Phase::Available(view) = mem::replace(&mut self.phase, Phase::Pending) {
+            if self.keep_on_reload {
+                self.stale = Some(view);
+            }
+        }
+
+        self.retry_at = None;
+        self.reload_error = None;
+        self.error_view = None;
+        self.details = None;
+        self.rx = rx;
+        self.stop_tx = stop_tx;
+        self.stop_rx = stop_rx.clone();
+        self.token = token;
+
+        (SendWrapper::new(tx), stop_rx)
+    }
+
+    /// Show the previous child view again after a reload failed or has been
+    /// cancelled. Returns whether there has been a previous child view.
+    fn restore_stale(&mut self, error: Option<E>) -> bool {
+        match self.stale.take() {
+            Some(view) => {
+                self.phase = Phase::Available(view);
+                self.reload_error = error;
+                self.retry_at = None;
+                self.stop_tickers();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replace the error with the fallback child view once the content failed to load.
+    fn check_fallback(&mut self) {
+        let fallback_fn = match (&self.phase, &self.fallback_fn) {
+            (Phase::Error(_), Some(fallback_fn)) => fallback_fn,
+            _ => return,
+        };
+
+        if let Phase::Error(err) = mem::replace(&mut self.phase, Phase::Pending) {
+            self.phase = Phase::Available(fallback_fn(&err));
+            self.stop_tickers();
+
+            if let Some(ref callback) = self.fallback_callback {
+                let callback = Arc::clone(callback);
+                match self.sink.send(Box::new(move |siv| callback(siv, err))) {
+                    Ok(_) => {}
+                    Err(send_err) => {
+                        warn!("Could not send callback to cursive. It probably has been dropped before the asynchronous initialization of a view has been finished: {}", send_err);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Stop the tickers of the current load, as nothing is animated anymore.
+    fn stop_tickers(&mut self) {
+        // replacing the sender stops the tickers
+        let (stop_tx, stop_rx) = channel::bounded(1);
+        self.stop_tx = stop_tx;
+        self.stop_rx = stop_rx;
+    }
+
+    /// Create the custom error view once the content failed to load.
+    fn check_error_view(&mut self) {
+        if let (Phase::Error(ref err), None, Some(ref error_view_fn)) =
+            (&self.phase, &self.error_view, &self.error_view_fn)
+        {
+            let mut view = error_view_fn(err);
+            // the view appears after the focus has been given, e.g. to the buttons of
+            // a dialog
+            view.take_focus(Direction::none()).ok();
+            self.error_view = Some(view);
+        }
+    }
+
+    /// The custom error view, once it has been created.
+    pub(crate) fn error_view_mut(&mut self) -> Option<&mut Box<dyn View>> {
+        self.error_view.as_mut()
+    }
+
+    /// The view to show if there is no child view, either the custom error view,
+    /// the error with its details or the animation.
+    fn placeholder(&self) -> &dyn View {
+        match (&self.error_view, &self.details) {
+            (Some(view), _) => view.as_ref(),
+            (None, Some(details)) => details,
+            (None, None) => &self.loading,
+        }
+    }
+
+    fn placeholder_mut(&mut self) -> &mut dyn View {
+        match (&mut self.error_view, &mut self.details) {
+            (Some(view), _) => view.as_mut(),
+            (None, Some(details)) => details,
+            (None, None) => &mut self.loading,
+        }
+    }
+
+    /// The child view to show instead of an animation, if any.
+    fn content(&self) -> Option<&T> {
+        match self.phase {
+            Phase::Available(ref view) => Some(view),
+            Phase::Pending => self.stale.as_ref(),
+            _ => None,
+        }
+    }
+
+    fn content_mut(&mut self) -> Option<&mut T> {
+        match self.phase {
+            Phase::Available(ref mut view) => Some(view),
+            Phase::Pending => self.stale.as_mut(),
+            _ => None,
+        }
+    }
+
+    /// Show a panic of the loader code as an error, unless panics should abort.
+    /// Then it is resumed on the cursive thread.
+    fn recover(&self, result: thread::Result<Polled<T, E>>) -> Polled<T, E> {
+        result.unwrap_or_else(|payload| {
+            if self.abort_on_panic {
+                panic::resume_unwind(payload);
+            }
+            Polled::Error((self.panic_error)(unwind::message(payload.as_ref())))
+        })
+    }
+
+    fn remaining(&self) -> Option<Duration> {
+        self.timeout
+            .map(|timeout| timeout.saturating_sub(self.started.elapsed()))
+    }
+
+    /// Give up loading once the timeout expired. Returns whether the error
+    /// animation is shown now.
+    fn check_timeout(&mut self) -> bool {
+        if let (Phase::Pending, Some(remaining), Some(into_error)) =
+            (&self.phase, self.remaining(), self.timeout_error)
+        {
+            // the attempts of the retry policy and the waits between them share the timeout
+            if remaining == Duration::ZERO {
+                self.token.cancel();
+
+                let err = into_error(self.timeout_msg.clone());
+                if self.revalidate && self.stale.is_some() {
+                    self.restore_stale(Some(err));
+                    return false;
+                }
+
+                self.phase = Phase::Error(err);
+                self.retry_at = None;
+                self.stale = None;
+
+                // the polling has stopped, keep the error animation running
+                notify::animation_ticker(&self.sink, self.stop_rx.clone());
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Draw the loading animation or the reload error over the top row of the
+    /// child view.
+    fn draw_overlay(&self, printer: &Printer) {
+        match self.phase {
+            Phase::Pending if self.revalidate => printer.print_styled((0, 0), &self.overlay),
+            Phase::Available(_) => {
+                if let Some(ref err) = self.reload_error {
+                    let badge = utils::error_badge(&err.to_string());
+                    let x = printer.size.x.saturating_sub(badge.width());
+                    printer.print_styled((x, 0), &badge);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub(crate) fn draw(&self, printer: &Printer) {
+        match self.content() {
+            Some(view) => {
+                view.draw(printer);
+                self.draw_overlay(printer);
+            }
+            None => self.placeholder().draw(printer),
+        }
+    }
+
+    pub(crate) fn layout(&mut self, size: Vec2) {
+        self.size = size;
+
+        match self.content_mut() {
+            Some(view) => view.layout(size),
+            None => self.placeholder_mut().layout(size),
+        }
+    }
+
+    pub(crate) fn needs_relayout(&self) -> bool {
+        match self.phase {
+            Phase::Available(ref view) => view.needs_relayout(),
+            _ => true,
+        }
+    }
+
+    pub(crate) fn on_event(&mut self, ev: Event) -> Handled {
+        if let Phase::Available(ref mut view) = self.phase {
+            return Handled::Event(view.on_event(ev));
+        }
+
+        // the shown view gets the first chance to handle the event, e.g. a key of
+        // the stale content or a button of the error view
+        let result = match self.content_mut() {
+            Some(view) => view.on_event(ev.clone()),
+            None => self.placeholder_mut().on_event(ev.clone()),
+        };
+
+        match (&self.phase, result) {
+            (_, EventResult::Consumed(cb)) => Handled::Event(EventResult::Consumed(cb)),
+            (Phase::Pending, _) if self.cancel_event.as_ref() == Some(&ev) => Handled::Cancel,
+            (Phase::Error(_), _) | (Phase::Cancelled, _)
+                if self.is_retryable() && self.is_retry_event(&ev) =>
+            {
+                Handled::Retry
+            }
+            (_, result) => Handled::Event(result),
+        }
+    }
+
+    pub(crate) fn call_on_any(&mut self, sel: &Selector, cb: AnyCb) {
+        match self.content_mut() {
+            Some(view) => view.call_on_any(sel, cb),
+            None => self.placeholder_mut().call_on_any(sel, cb),
+        }
+    }
+
+    pub(crate) fn focus_view(&mut self, sel: &Selector) -> Result<EventResult, ViewNotFound> {
+        match self.content_mut() {
+            Some(view) => view.focus_view(sel),
+            None => self.placeholder_mut().focus_view(sel),
+        }
+    }
+
+    pub(crate) fn take_focus(&mut self, source: Direction) -> Result<EventResult, CannotFocus> {
+        match self.phase {
+            Phase::Available(ref mut view) => view.take_focus(source),
+            Phase::Error(_) if self.error_view.is_some() => {
+                let result = self.error_view.as_mut().unwrap().take_focus(source);
+                if result.is_err() && self.loader.is_some() && self.retry_event.is_some() {
+                    // the retry event is received without a focusable error view too
+                    return Ok(EventResult::Consumed(None));
+                }
+                result
+            }
+            // take the focus to toggle the details of the error
+            Phase::Error(_) if self.details.as_ref().is_some_and(ErrorDetails::has_details) => {
+                Ok(EventResult::Consumed(None))
+            }
+            // the stale content stays usable while it is revalidated
+            Phase::Pending if self.stale.is_some() => {
+                let result = self.stale.as_mut().unwrap().take_focus(source);
+                if result.is_err()
+                    && (self.cancel_event.is_some()
+                        || self.loader.is_some() && self.retry_event.is_some())
+                {
+                    // the cancel and retry events are received without a focusable child too
+                    return Ok(EventResult::Consumed(None));
+                }
+                result
+            }
+            // take the focus to receive the cancel event
+            Phase::Pending if self.cancel_event.is_some() => Ok(EventResult::Consumed(None)),
+            // keep the focus once taken, as the view may fail and is retried by an
+            // event afterwards
+            _ if self.loader.is_some() && self.retry_event.is_some() => {
+                Ok(EventResult::Consumed(None))
+            }
+            _ => Err(CannotFocus),
+        }
+    }
+
+    pub(crate) fn important_area(&self, view_size: Vec2) -> Rect {
+        match self.content() {
+            Some(view) => view.important_area(view_size),
+            None => self.placeholder().important_area(view_size),
+        }
+    }
+}
+
+impl<T: View, E> Drop for Lifecycle<T, E> {
+    fn drop(&mut self) {
+        self.token.cancel();
+
+        match self.stop_tx.send(()) {
+            Ok(_) => {}
+            Err(send_err) => warn!(
+                "Refreshing thread has been dropped before view has, this has no impact on your code and is a bug: {}",
+                send_err
+            ),
+        }
+    }
+}
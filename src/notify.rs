@@ -0,0 +1,292 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Wake, Waker};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossbeam::channel::{self, Receiver, Sender, TryRecvError, TrySendError};
+use cursive_core::{CbSink, Cursive};
+use log::warn;
+use send_wrapper::SendWrapper;
+
+use crate::cancel::CancellationToken;
+use crate::infinite::FPS;
+
+/// A handle to wake up an `AsyncView` or `AsyncProgressView` created with
+/// `new_with_notifier`. Such a view only calls its poll function after it has
+/// been notified, instead of polling on every frame.
+///
+/// The notifier can be cloned and sent to any thread. Multiple notifications
+/// issued between two polls of the view are merged into a single poll. A notifier
+/// may be given to several views, a notification wakes all of them.
+///
+/// ```
+/// use std::thread;
+/// use std::time::Duration;
+///
+/// use crossbeam::channel::{unbounded, TryRecvError};
+/// use cursive::views::TextView;
+/// use cursive::{Cursive, CursiveExt};
+/// use cursive_async_view::{AsyncState, AsyncView, Notifier};
+///
+/// let mut siv = Cursive::default();
+/// let notifier = Notifier::new();
+/// let (tx, rx) = unbounded();
+///
+/// let producer = notifier.clone();
+/// thread::spawn(move || {
+///     thread::sleep(Duration::from_secs(5));
+///     tx.send("Loaded without polling!").unwrap();
+///
+///     // wake up the async view, so it polls the channel
+///     producer.notify();
+/// });
+///
+/// let async_view = AsyncView::new_with_notifier(&mut siv, notifier, move || {
+///     match rx.try_recv() {
+///         Ok(msg) => AsyncState::Available(TextView::new(msg)),
+///         Err(TryRecvError::Empty) => AsyncState::Pending,
+///         Err(TryRecvError::Disconnected) => AsyncState::Error("Producer died!".to_string()),
+///     }
+/// });
+///
+/// siv.add_layer(async_view);
+/// // siv.run();
+/// ```
+#[derive(Clone)]
+pub struct Notifier {
+    // every view this notifier has been given to waits on its own channel, so
+    // the views do not steal wakeups from each other
+    views: Arc<Mutex<Vec<Sender<()>>>>,
+}
+
+impl Notifier {
+    /// Create a new notifier which is not bound to any view yet.
+    pub fn new() -> Self {
+        Self {
+            views: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Wake up the views this notifier was given to. The views will call their
+    /// poll function on the next frame.
+    pub fn notify(&self) {
+        self.views
+            .lock()
+            .unwrap()
+            .retain(|tx| match tx.try_send(()) {
+                // a full channel means a poll is already due
+                Ok(_) | Err(TrySendError::Full(_)) => true,
+                // the view has been loaded or dropped
+                Err(TrySendError::Disconnected(_)) => false,
+            });
+    }
+
+    /// Create a `std::task::Waker` which notifies the view when woken. This
+    /// is useful to hand the notifier to code expecting futures wakers.
+    pub fn waker(&self) -> Waker {
+        Waker::from(Arc::new(self.clone()))
+    }
+
+    // a new channel of a view, which receives a message once it is notified
+    fn subscribe(&self) -> Receiver<()> {
+        let (tx, rx) = channel::bounded(1);
+        self.views.lock().unwrap().push(tx);
+        rx
+    }
+}
+
+impl Default for Notifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Wake for Notifier {
+    fn wake(self: Arc<Self>) {
+        self.notify();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.notify();
+    }
+}
+
+type PollCb = Box<dyn FnOnce(&mut Cursive)>;
+
+/// A poll waiting for the next notification, see `schedule_poll`.
+pub(crate) struct PollRequest {
+    instant: Instant,
+    token: CancellationToken,
+    poll: SendWrapper<PollCb>,
+}
+
+/// Determines when the poll function of a view is called again.
+pub(crate) enum PollMode {
+    /// Poll on every frame, about 60 times a second.
+    Fps,
+
+    /// Poll only after the notifier has been notified. The polls are scheduled
+    /// by a waker thread, which also keeps the animation running. It stops once
+    /// this value has been dropped.
+    Notified { waker: Sender<PollRequest> },
+}
+
+impl PollMode {
+    pub(crate) fn notified(sink: &CbSink, notifier: Notifier) -> Self {
+        let (waker, requests) = channel::unbounded();
+        waker_thread(sink.clone(), notifier.subscribe(), requests);
+
+        PollMode::Notified { waker }
+    }
+
+    pub(crate) fn waker(&self) -> Option<Sender<PollRequest>> {
+        match self {
+            PollMode::Fps => None,
+            PollMode::Notified { waker } => Some(waker.clone()),
+        }
+    }
+}
+
+/// Run `poll` on the cursive thread as soon as the next poll is due. Without
+/// a `waker` this is the next frame, otherwise the waker thread runs `poll` on
+/// the next frame after a notification. Cancelling the `token` also ends the
+/// waiting.
+pub(crate) fn schedule_poll<F>(
+    sink: &CbSink,
+    instant: Instant,
+    waker: Option<Sender<PollRequest>>,
+    token: &CancellationToken,
+    poll: F,
+) where
+    F: FnOnce(&mut Cursive) + 'static,
+{
+    let poll: PollCb = Box::new(poll);
+    let request = PollRequest {
+        instant,
+        token: token.clone(),
+        poll: SendWrapper::new(poll),
+    };
+
+    match waker {
+        Some(waker) => {
+            // the waker thread only stops once the poll mode has been dropped,
+            // which is owned by the poll itself
+            waker.send(request).ok();
+        }
+        None => {
+            let sink = sink.clone();
+            thread::spawn(move || run_poll(&sink, request));
+        }
+    }
+}
+
+// wait for the next frame and run the poll on the cursive thread
+fn run_poll(sink: &CbSink, request: PollRequest) {
+    // ensure ~60fps
+    if let Some(duration) = FPS.checked_sub(request.instant.elapsed()) {
+        thread::sleep(duration);
+    }
+
+    let poll = request.poll;
+    match sink.send(Box::new(move |siv| (poll.take())(siv))) {
+        Ok(_) => {}
+        Err(send_err) => {
+            warn!("Could not send callback to cursive. It probably has been dropped before the asynchronous initialization of a view has been finished: {}", send_err);
+        }
+    }
+}
+
+/// Redraw cursive on every frame and run the requested polls after the view has
+/// been notified, until `requests` gets disconnected.
+fn waker_thread(sink: CbSink, wakeups: Receiver<()>, requests: Receiver<PollRequest>) {
+    thread::spawn(move || {
+        let mut next_frame = Instant::now() + *FPS;
+
+        loop {
+            let frame = channel::at(next_frame);
+            let request = channel::select! {
+                recv(requests) -> request => match request {
+                    Ok(request) => request,
+                    Err(_) => break,
+                },
+                recv(frame) -> _ => {
+                    if !redraw(&sink) {
+                        break;
+                    }
+                    next_frame = Instant::now() + *FPS;
+                    continue;
+                }
+            };
+
+            // either there is new state or the view is not interested anymore,
+            // poll in both cases so the poll function gets dropped on the
+            // cursive thread
+            loop {
+                let frame = channel::at(next_frame);
+                channel::select! {
+                    recv(wakeups) -> _ => break,
+                    recv(request.token.signal()) -> _ => break,
+                    recv(frame) -> _ => {
+                        redraw(&sink);
+                        next_frame = Instant::now() + *FPS;
+                    }
+                }
+            }
+
+            run_poll(&sink, request);
+        }
+    });
+}
+
+/// Redraw cursive on every frame until `stop` receives a message or gets
+/// disconnected.
+pub(crate) fn animation_ticker(sink: &CbSink, stop: Receiver<()>) {
+    let sink = sink.clone();
+    thread::spawn(move || loop {
+        thread::sleep(*FPS);
+
+        match stop.try_recv() {
+            Err(TryRecvError::Empty) => {
+                if !redraw(&sink) {
+                    break;
+                }
+            }
+            _ => break,
+        }
+    });
+}
+
+// wake up cursive, so it draws the next frame; fails once cursive has been dropped
+fn redraw(sink: &CbSink) -> bool {
+    match sink.send(Box::new(|_| {})) {
+        Ok(_) => true,
+        Err(send_err) => {
+            warn!(
+                "Cursive has been dropped before AsyncView has been: {}",
+                send_err
+            );
+            false
+        }
+    }
+}
+
+/// Mark `due` and wake up cursive every `interval`, until `stop` is cancelled.
+pub(crate) fn refresh_timer(
+    sink: &CbSink,
+    interval: Duration,
+    due: Arc<AtomicBool>,
+    stop: CancellationToken,
+) {
+    let sink = sink.clone();
+    thread::spawn(move || {
+        while !stop.wait_timeout(interval) {
+            due.store(true, Ordering::Release);
+
+            // the view checks whether a refresh is due on the next layout
+            if !redraw(&sink) {
+                break;
+            }
+        }
+    });
+}
@@ -1,29 +1,67 @@
-use crossbeam::channel::{bounded, unbounded, Receiver, Sender};
+use crossbeam::channel::{unbounded, TryRecvError};
 use cursive_core::direction::Direction;
 use cursive_core::event::{AnyCb, Event, EventResult};
 use cursive_core::theme::PaletteColor;
 use cursive_core::utils::markup::StyledString;
 use cursive_core::view::{CannotFocus, Selector, View, ViewNotFound};
-use cursive_core::views::TextView;
-use cursive_core::{Cursive, Printer, Rect, Vec2};
+use cursive_core::{CbSink, Cursive, Printer, Rect, Vec2};
 use interpolation::Ease;
-use log::warn;
-use num::clamp;
-use send_wrapper::SendWrapper;
+use unicode_width::UnicodeWidthStr;
 
+use futures_core::Stream;
+
+use std::cell::RefCell;
+use std::fmt::Display;
+#[cfg(feature = "tokio")]
+use std::future::Future;
+use std::panic;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::task::Poll;
 use std::thread;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use crate::{infinite::FPS, utils, AsyncView};
+use crate::cancel::CancellationToken;
+use crate::details::ErrorDetails;
+use crate::estimate::Estimator;
+use crate::executor::LocalStream;
+#[cfg(feature = "tokio")]
+use crate::executor::{AbortOnDrop, LocalFuture};
+use crate::infinite::{default_animation, AnimationFrame};
+use crate::lifecycle::{self, Change, Handled, Lifecycle, Phase, Polled};
+use crate::notify::{Notifier, PollMode};
+use crate::reporter::ProgressReporter;
+use crate::retry::RetryPolicy;
+use crate::status::Progress;
+use crate::unwind;
+use crate::utils;
 
 /// An enum to be returned by the `poll_ready` callback, with additional information about the creation progress.
-pub enum AsyncProgressState<V: View> {
+pub enum AsyncProgressState<V: View, E = String> {
     /// Indicates a not completed creation, which is still ongoing. Also reports the progress made as float value between 0 and 1.
     Pending(f32),
-    /// Indicates a not completed creation, which cannot proceed further. Contains an error message to be displayed for the user.
-    Error(String),
+    /// Indicates a not completed creation, which is still ongoing. Reports the progress made together with a status message, see `Progress`.
+    Progress(Progress),
+    /// Indicates a not completed creation, which cannot proceed further. Contains an error to be displayed for the user.
+    Error(E),
     /// Indicates a completed creation. Contains the new child view.
     Available(V),
+    /// Indicates a creation which has been cancelled, e.g. by the user. See `AsyncProgressView::cancel` for details.
+    Cancelled,
+}
+
+/// An item of a progress stream given to `AsyncProgressView::from_stream`. A stream
+/// yields any number of `Pending` or `Progress` items and ends with either an `Available` or an
+/// `Error` item.
+pub enum ProgressEvent<D, E = String> {
+    /// Reports the progress made as float value between 0 and 1.
+    Pending(f32),
+    /// Reports the progress made together with a status message, see `Progress`.
+    Progress(Progress),
+    /// The data for the child view, the stream is not polled anymore afterwards.
+    Available(D),
+    /// The creation failed with the given error, the stream is not polled anymore afterwards.
+    Error(E),
 }
 
 /// This struct contains the content of a single frame for `AsyncProgressView` with some metadata about the current frame.
@@ -33,11 +71,92 @@ pub struct AnimationProgressFrame {
     /// Current position of the loading bar.
     pub pos: usize,
     /// Index of the next frame to be drawn, useful if you want to interpolate between two states of progress.
+    /// An error animation returns the current `frame_idx` once it is complete.
     pub next_frame_idx: usize,
 }
 
 /// The default progress animation for a `AsyncProgressView`.
 ///
+/// A status message reported with the progress is shown centered below the bar,
+/// followed by the amount done like `34.2 MiB / 120 MiB` and the estimated time left
+/// like `42% · 12s left`, if the view has been created `with_estimate`. If the view is
+/// only a single line high, they are shown next to the bar instead, shortened to half
+/// of the available width. An indeterminate progress, e.g. a phase of unknown length
+/// or an amount with an unknown total, is shown with the animation of
+/// `default_animation` instead of the bar. Once the progress can be told, the bar
+/// grows smoothly from the start.
+///
+/// ```
+/// use cursive_async_view::{default_progress, Progress};
+///
+/// let progress = Progress::new(0.5).with_message("Resolving dependencies…");
+///
+/// let frame = default_progress(40, 2, &progress, 0, 0);
+/// let lines = frame.content.source().lines().collect::<Vec<_>>();
+/// assert_eq!(lines[1].trim(), "Resolving dependencies…");
+///
+/// // a single line is shared by the bar and the message
+/// let frame = default_progress(60, 1, &progress, 0, 0);
+/// assert_eq!(frame.content.width(), 60);
+/// assert!(frame.content.source().ends_with(" Resolving dependencies…"));
+/// ```
+///
+/// The rows left below show the active children of a progress with child tasks as
+/// indented bars, labelled with their status messages. Limit the height of the view
+/// with `AsyncProgressView::with_height` to show fewer of them:
+///
+/// ```
+/// use cursive_async_view::{default_progress, Progress};
+///
+/// let progress = Progress::default()
+///     .with_child(Progress::new(1.0).with_message("fetch"))
+///     .with_child(Progress::new(0.5).with_message("compile"))
+///     .with_child(Progress::new(0.2).with_message("test"));
+///
+/// let frame = default_progress(40, 10, &progress, 0, 0);
+/// let lines = frame.content.source().lines().collect::<Vec<_>>();
+/// // finished children are not shown
+/// assert_eq!(lines.len(), 3);
+/// assert!(lines[1].starts_with("  compile ━"));
+/// assert!(lines[2].starts_with("  test ━"));
+/// assert!(lines.iter().all(|line| line.chars().count() == 40));
+///
+/// // only as many children as fit into the height
+/// let frame = default_progress(40, 2, &progress, 0, 0);
+/// assert_eq!(frame.content.source().lines().count(), 2);
+/// ```
+///
+/// The progress does not have to grow steadily. If it goes down, e.g. because a
+/// load is retried, a phase restarts or a total has been revised, the bar moves back
+/// to the new value:
+///
+/// ```
+/// use cursive_async_view::{default_progress, Progress};
+///
+/// // the bar has been drawn up to column 30, now the progress went back to a quarter
+/// let progress = Progress::new(0.25);
+/// let mut pos = 30;
+/// let mut frame_idx = 0;
+/// for _ in 0..30 {
+///     let frame = default_progress(40, 1, &progress, pos, frame_idx);
+///     assert_eq!(frame.content.width(), 40);
+///     assert!(frame.pos <= pos && frame.pos >= 10);
+///     pos = frame.pos;
+///     frame_idx = frame.next_frame_idx;
+/// }
+/// assert_eq!(pos, 10);
+///
+/// // a reset to zero empties the bar, even if it was drawn wider before
+/// let progress = Progress::new(0.0);
+/// let mut pos = 50;
+/// for frame_idx in 0..30 {
+///     let frame = default_progress(40, 1, &progress, pos, frame_idx);
+///     assert_eq!(frame.content.width(), 40);
+///     pos = frame.pos;
+/// }
+/// assert_eq!(pos, 0);
+/// ```
+///
 /// # Creating your own progress function
 ///
 /// As an example a very basic progress function would look like this:
@@ -47,17 +166,23 @@ pub struct AnimationProgressFrame {
 /// use cursive::{Cursive, CursiveExt};
 /// use cursive::views::TextView;
 /// use cursive::utils::markup::StyledString;
-/// use cursive_async_view::{AnimationProgressFrame, AsyncProgressView, AsyncProgressState};
+/// use cursive_async_view::{AnimationProgressFrame, AsyncProgressView, AsyncProgressState, Progress};
 ///
 /// fn my_progress_function(
 ///     _width: usize,
 ///     _height: usize,
-///     progress: f32,
+///     progress: &Progress,
 ///     _pos: usize,
 ///     frame_idx: usize,
 /// ) -> AnimationProgressFrame {
+///     let percent = format!("{:.0}%", progress.value() * 100.0);
+///     let content = match progress.message() {
+///         Some(msg) => format!("{} {}", percent, msg),
+///         None => percent,
+///     };
+///
 ///     AnimationProgressFrame {
-///         content: StyledString::plain(format!("{:.0}%", progress * 100.0)),
+///         content: StyledString::plain(content),
 ///         pos: 0,
 ///         next_frame_idx: frame_idx,
 ///     }
@@ -65,7 +190,7 @@ pub struct AnimationProgressFrame {
 ///
 /// let mut siv = Cursive::default();
 /// let start = std::time::Instant::now();
-/// let async_view = AsyncProgressView::new(&mut siv, move || {
+/// let async_view: AsyncProgressView<TextView> = AsyncProgressView::new(&mut siv, move || {
 ///     if start.elapsed().as_secs() > 5 {
 ///         AsyncProgressState::Pending(start.elapsed().as_secs() as f32 /5f32)
 ///     } else {
@@ -75,62 +200,193 @@ pub struct AnimationProgressFrame {
 /// .with_progress_fn(my_progress_function);
 /// ```
 ///
-/// The progress function will display the progress in percent as a simple string.
+/// The progress function will display the progress in percent as a simple string,
+/// followed by the status message, if any.
 ///
 /// The `width` and `height` parameters contain the maximum size the content may have
-/// (in characters). The `progress` parameter is guaranteed to be a `f32` between 0 and 1.
-/// The `pos` and `frame_idx` parameter are always from the animation frame of the previous iteration.
+/// (in characters). The value of the `progress` parameter is guaranteed to be a `f32`
+/// between 0 and 1. The `pos` and `frame_idx` parameter are always from the animation
+/// frame of the previous iteration.
 pub fn default_progress(
     width: usize,
-    _height: usize,
-    progress: f32,
+    height: usize,
+    progress: &Progress,
     pos: usize,
     frame_idx: usize,
 ) -> AnimationProgressFrame {
-    assert!(progress >= 0.0);
-    assert!(progress <= 1.0);
+    let mut info = Vec::new();
+    if let Some(msg) = progress.message() {
+        info.push(msg.lines().next().unwrap_or_default().to_string());
+    }
+    let stats = progress
+        .format_amount()
+        .into_iter()
+        .chain(
+            progress
+                .eta()
+                .map(|eta| utils::estimate(progress.value(), eta)),
+        )
+        .collect::<Vec<_>>();
+    if !stats.is_empty() {
+        info.push(stats.join(" · "));
+    }
 
-    let foreground = PaletteColor::Highlight;
-    let background = PaletteColor::HighlightInactive;
-    let symbol = "━";
+    if height <= 1 && !info.is_empty() {
+        let label = utils::summary_line(&info.join(" · "), width / 2);
+        let bar_width = width.saturating_sub(label.width() + 1);
+        let mut frame = progress_bar(bar_width, progress, pos, frame_idx);
+        frame.content.append_plain(format!(" {}", label));
+        return frame;
+    }
+
+    let mut frame = progress_bar(width, progress, pos, frame_idx);
+    for line in &info {
+        frame.content.append_plain(utils::message_line(line, width));
+    }
+
+    // the rows left show what the children are working on
+    let mut rows = height.saturating_sub(1 + info.len());
+    child_bars(&mut frame.content, progress, width, 1, &mut rows, frame_idx);
+    frame
+}
+
+// the active children of `progress` and their own active children as bars indented
+// by `depth`, as long as there are `rows` left
+fn child_bars(
+    content: &mut StyledString,
+    progress: &Progress,
+    width: usize,
+    depth: usize,
+    rows: &mut usize,
+    frame_idx: usize,
+) {
+    let indent = 2 * depth;
+    for (_, child) in progress.children().filter(|(_, child)| child.is_active()) {
+        if *rows == 0 {
+            return;
+        }
+        *rows -= 1;
+
+        content.append_plain(format!("\n{}", utils::repeat_str(" ", indent)));
+        let mut bar_width = width.saturating_sub(indent);
+        if let Some(msg) = child.message() {
+            let label = utils::summary_line(msg, bar_width / 3);
+            bar_width = bar_width.saturating_sub(label.width() + 1);
+            content.append_plain(format!("{} ", label));
+        }
+
+        if child.is_indeterminate() {
+            content.append(default_animation(bar_width, 1, frame_idx).content);
+        } else {
+            let end = (bar_width as f32 * child.value()) as usize;
+            content.append(bar_line(bar_width, end));
+        }
+
+        child_bars(content, child, width, depth + 1, rows, frame_idx);
+    }
+}
+
+// the bar of `default_progress`, moving from `pos` towards `progress`
+fn progress_bar(
+    width: usize,
+    progress: &Progress,
+    pos: usize,
+    frame_idx: usize,
+) -> AnimationProgressFrame {
+    if progress.is_indeterminate() {
+        let AnimationFrame {
+            content,
+            next_frame_idx,
+        } = default_animation(width, 1, frame_idx);
+        return AnimationProgressFrame {
+            content,
+            pos: 0,
+            next_frame_idx,
+        };
+    }
+
+    let progress = progress.value();
 
     let duration = 30; //one second
     let durationf = duration as f64;
 
-    let next_pos = width as f32 * progress;
-    let offset = next_pos as usize - pos;
+    // the bar may have been drawn wider before, e.g. next to a shorter message
+    let pos = pos.min(width);
+    let next_pos = (width as f32 * progress) as usize;
+    // negative if the progress went down, the bar moves backwards then
+    let offset = next_pos as f64 - pos as f64;
 
     let idx = frame_idx % duration;
     let idxf = idx as f64;
     let factor = (idxf / durationf).circular_out();
-    let end = (pos as f64 + offset as f64 * factor) as usize;
-
-    let mut result = StyledString::new();
-    result.append_styled(utils::repeat_str(symbol, end), foreground);
-    result.append_styled(utils::repeat_str(symbol, width - end), background);
+    let end = (pos as f64 + offset * factor) as usize;
 
     AnimationProgressFrame {
-        content: result,
+        content: bar_line(width, end),
         pos: end,
-        next_frame_idx: idx + 1,
+        // keep counting, so indeterminate child bars run through the whole cycle of
+        // `default_animation`
+        next_frame_idx: (frame_idx + 1) % (2 * duration),
     }
 }
 
-/// The default error animation for a `AsyncProgressView`.
+// a bar `width` columns wide, filled up to `end`
+fn bar_line(width: usize, end: usize) -> StyledString {
+    let foreground = PaletteColor::Highlight;
+    let background = PaletteColor::HighlightInactive;
+    let symbol = "━";
+
+    let mut result = StyledString::new();
+    result.append_styled(utils::repeat_str(symbol, end), foreground);
+    result.append_styled(utils::repeat_str(symbol, width - end), background);
+    result
+}
+
+/// The default error animation for a `AsyncProgressView`. It reveals the first line of the
+/// error, shortened to the available width.
+///
+/// If the error does not fit into this line, e.g. a stack trace or the body of an
+/// HTTP response, the complete message can be expanded below by pressing `Enter`
+/// once the error has been revealed and the view has the focus. The expanded
+/// details scroll if they exceed the height of the view. This works with custom
+/// error animations as well, as soon as they are complete.
+///
+/// Like `default_error`, the message is measured and cut by its display width and
+/// grapheme clusters, so wide and zero-width characters are revealed correctly:
+///
+/// ```
+/// use cursive_async_view::default_progress_error;
+///
+/// for msg in ["読み込みに失敗しました ❌", "Cafe\u{301} closed\u{200b}"] {
+///     for frame_idx in 0..=30 {
+///         // every frame fills the width exactly
+///         let frame = default_progress_error(msg, 25, 1, 0.5, 0, frame_idx);
+///         assert_eq!(frame.content.width(), 25);
+///     }
+///
+///     // the final frame reveals the complete message and completes the animation
+///     let frame = default_progress_error(msg, 25, 1, 0.5, 0, 30);
+///     assert_eq!(frame.content.source().trim(), msg);
+///     assert_eq!(frame.next_frame_idx, 30);
+/// }
+/// ```
 ///
 /// # Creating your own error animation
 ///
-/// The creation is very similar to the progress animation, but the error message is given now as the first parameter.
+/// The creation is very similar to the progress animation, but the error is given now as the first parameter.
+/// The error can be of any type implementing `Display`, it is the error type of the `AsyncProgressView`.
+/// Like for `default_error`, the animation is complete once it returns the `frame_idx` it has been
+/// called with as `next_frame_idx`.
 ///
 /// ```
-/// use crossbeam::channel::Sender;
+/// use std::fmt::Display;
 /// use cursive::{Cursive, CursiveExt};
 /// use cursive::views::TextView;
 /// use cursive::utils::markup::StyledString;
 /// use cursive_async_view::{AnimationProgressFrame, AsyncProgressView, AsyncProgressState};
 ///
-/// fn my_error_function(
-///     msg: String,
+/// fn my_error_function<E: Display>(
+///     err: &E,
 ///     _width: usize,
 ///     _height: usize,
 ///     progress: f32,
@@ -138,7 +394,7 @@ pub fn default_progress(
 ///     frame_idx: usize,
 /// ) -> AnimationProgressFrame {
 ///     AnimationProgressFrame {
-///         content: StyledString::plain(format!("Error: {}", msg)),
+///         content: StyledString::plain(format!("Error: {}", err)),
 ///         pos: 0,
 ///         next_frame_idx: frame_idx,
 ///     }
@@ -157,8 +413,8 @@ pub fn default_progress(
 /// })
 /// .with_error_fn(my_error_function);
 /// ```
-pub fn default_progress_error(
-    msg: String,
+pub fn default_progress_error<E: Display + ?Sized>(
+    err: &E,
     width: usize,
     _height: usize,
     progress: f32,
@@ -177,27 +433,13 @@ pub fn default_progress_error(
     let idx = frame_idx;
     let idxf = idx as f64;
     let factor = (idxf / durationf).circular_in_out();
-    let mut offset = width as f64 * factor;
-
-    let padding = width.saturating_sub(msg.len()) / 2;
-    let mut background_content = format!(
-        "{}{}{}",
-        utils::repeat_str(" ", padding),
-        msg,
-        utils::repeat_str(" ", padding),
-    );
-    // Check for non-char symbols
-    if background_content
-        .as_str()
-        .get(0..offset as usize)
-        .is_none()
-    {
-        offset += 2_f64;
-    }
+    let offset = width as f64 * factor;
+
+    let msg = utils::summary_line(&err.to_string(), width);
+    let background_content = utils::center(&msg, width);
     let end = pos + offset as usize;
-    background_content.truncate(offset as usize);
     let mut result = StyledString::new();
-    result.append_plain(background_content);
+    result.append_plain(utils::cut(&background_content, offset as usize));
     result.append_styled(
         utils::repeat_str(symbol, {
             if (pos + offset as usize) < width {
@@ -216,10 +458,46 @@ pub fn default_progress_error(
     AnimationProgressFrame {
         content: result,
         pos,
-        next_frame_idx: frame_idx + 1,
+        // the message has been revealed completely
+        next_frame_idx: (frame_idx + 1).min(duration),
     }
 }
 
+/// The default cancel animation for a `AsyncProgressView`. It reveals the message
+/// `Loading cancelled` in the same way `default_progress_error` reveals an error.
+///
+/// A cancel function is called with the same arguments as an error function, apart
+/// from the error. Wrap `default_progress_error` to display a different message:
+///
+/// ```
+/// use cursive::{Cursive, CursiveExt};
+/// use cursive::views::TextView;
+/// use cursive_async_view::{default_progress_error, AsyncProgressView, AsyncProgressState};
+///
+/// let mut siv = Cursive::default();
+/// let start = std::time::Instant::now();
+/// let async_view: AsyncProgressView<TextView> = AsyncProgressView::new(&mut siv, move || {
+///     if start.elapsed().as_secs() < 5 {
+///         AsyncProgressState::Pending(start.elapsed().as_secs() as f32 / 5f32)
+///     } else {
+///         AsyncProgressState::Available(TextView::new("Loaded!"))
+///     }
+/// })
+/// .with_cancellable()
+/// .with_cancel_fn(|width, height, progress, pos, frame_idx| {
+///     default_progress_error("Aborted by user", width, height, progress, pos, frame_idx)
+/// });
+/// ```
+pub fn default_progress_cancel(
+    width: usize,
+    height: usize,
+    progress: f32,
+    pos: usize,
+    frame_idx: usize,
+) -> AnimationProgressFrame {
+    default_progress_error("Loading cancelled", width, height, progress, pos, frame_idx)
+}
+
 /// An `AsyncProgressView` is a wrapper view that displays a progress bar, until the
 /// child view is successfully created or an error in the creation progress occured.
 ///
@@ -245,7 +523,7 @@ pub fn default_progress_error(
 ///
 /// let mut siv = Cursive::default();
 /// let start = std::time::Instant::now();
-/// let async_view = AsyncProgressView::new(&mut siv, move || {
+/// let async_view: AsyncProgressView<TextView> = AsyncProgressView::new(&mut siv, move || {
 ///     if start.elapsed().as_secs() < 3 {
 ///         AsyncProgressState::Pending(start.elapsed().as_secs() as f32 / 3f32)
 ///     } else {
@@ -257,27 +535,43 @@ pub fn default_progress_error(
 /// // siv.run();
 /// ```
 ///
-pub struct AsyncProgressView<T: View> {
-    view: AsyncProgressState<T>,
-    loading: TextView,
-    progress_fn: Box<
-        dyn Fn(usize, usize, f32, usize, usize) -> AnimationProgressFrame + Send + Sync + 'static,
-    >,
-    error_fn: Box<
-        dyn Fn(String, usize, usize, f32, usize, usize) -> AnimationProgressFrame
-            + Send
-            + Sync
-            + 'static,
-    >,
+pub struct AsyncProgressView<T: View, E = String> {
+    core: Lifecycle<T, E>,
+    // the latest progress, while the view is pending
+    progress: Progress,
+    progress_fn: ProgressFn,
+    error_fn: ProgressErrorFn<E>,
+    cancel_fn: CancelFn,
     width: Option<usize>,
     height: Option<usize>,
-    view_rx: Receiver<AsyncProgressState<T>>,
     frame_index: usize,
-    dropped: Sender<()>,
     pos: usize,
+    estimate: bool,
+    estimator: Estimator,
+}
+
+type ProgressFn = Box<
+    dyn Fn(usize, usize, &Progress, usize, usize) -> AnimationProgressFrame + Send + Sync + 'static,
+>;
+type CancelFn =
+    Box<dyn Fn(usize, usize, f32, usize, usize) -> AnimationProgressFrame + Send + Sync + 'static>;
+type ProgressErrorFn<E> = Box<
+    dyn Fn(&E, usize, usize, f32, usize, usize) -> AnimationProgressFrame + Send + Sync + 'static,
+>;
+
+impl<T: View, E> From<AsyncProgressState<T, E>> for Polled<T, E> {
+    fn from(state: AsyncProgressState<T, E>) -> Self {
+        match state {
+            AsyncProgressState::Pending(value) => Polled::Pending(Some(Progress::new(value))),
+            AsyncProgressState::Progress(progress) => Polled::Pending(Some(progress)),
+            AsyncProgressState::Error(err) => Polled::Error(err),
+            AsyncProgressState::Available(view) => Polled::Available(view),
+            AsyncProgressState::Cancelled => Polled::Cancelled,
+        }
+    }
 }
 
-impl<T: View> AsyncProgressView<T> {
+impl<T: View, E: Display + Send + Sync + 'static> AsyncProgressView<T, E> {
     /// Create a new `AsyncProgressView` instance. The cursive reference is only used to
     /// update the screen when a progress update is received. In order to show the view,
     /// it has to be directly or indirectly added to a cursive layer like any other view.
@@ -287,89 +581,604 @@ impl<T: View> AsyncProgressView<T> {
     /// creation thread will get stuck.
     pub fn new<F>(siv: &mut Cursive, creator: F) -> Self
     where
-        F: FnMut() -> AsyncProgressState<T> + 'static,
+        E: From<String>,
+        F: FnMut() -> AsyncProgressState<T, E> + 'static,
+    {
+        Self::with_poll_mode(siv, PollMode::Fps, CancellationToken::new(), creator)
+    }
+
+    /// Create a new `AsyncProgressView` instance which only calls `creator` after
+    /// it has been woken by the given `notifier`. In order to show the view, it has
+    /// to be directly or indirectly added to a cursive layer like any other view.
+    ///
+    /// Compared to `new`, the `creator` function is not called on every frame, but
+    /// only once initially and then whenever new progress has been announced by
+    /// calling `Notifier::notify`. The progress bar keeps animating on its own timer
+    /// until the view has been loaded.
+    pub fn new_with_notifier<F>(siv: &mut Cursive, notifier: Notifier, creator: F) -> Self
+    where
+        E: From<String>,
+        F: FnMut() -> AsyncProgressState<T, E> + 'static,
     {
-        let (view_tx, view_rx) = unbounded();
-        let (error_tx, error_rx) = bounded(1);
+        let mode = PollMode::notified(siv.cb_sink(), notifier.clone());
+        let mut view = Self::with_poll_mode(siv, mode, CancellationToken::new(), creator);
+        view.core.notifier = Some(notifier);
+        view
+    }
 
-        Self::polling_cb(
-            siv,
-            Instant::now(),
-            SendWrapper::new(view_tx),
-            error_rx,
-            creator,
-        );
+    /// Create a new `AsyncProgressView` instance which can be retried after loading
+    /// failed. In order to show the view, it has to be directly or indirectly added
+    /// to a cursive layer like any other view.
+    ///
+    /// The `factory` is called once initially and once for every retry. It creates
+    /// a fresh `creator` function, which works like the one given to `new`. See
+    /// `with_retryable` for how to let the user retry.
+    ///
+    /// ```
+    /// use std::time::Instant;
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive::views::TextView;
+    /// use cursive_async_view::{AsyncProgressView, AsyncProgressState};
+    ///
+    /// let mut siv = Cursive::default();
+    /// let mut attempt = 0;
+    /// let async_view = AsyncProgressView::new_retryable(&mut siv, move || {
+    ///     attempt += 1;
+    ///     let failing = attempt == 1;
+    ///     let start = Instant::now();
+    ///
+    ///     move || {
+    ///         if start.elapsed().as_secs() < 2 {
+    ///             AsyncProgressState::Pending(start.elapsed().as_secs() as f32 / 2f32)
+    ///         } else if failing {
+    ///             AsyncProgressState::Error("Flaky network, press r to retry".to_string())
+    ///         } else {
+    ///             AsyncProgressState::Available(TextView::new("Second time's the charm!"))
+    ///         }
+    ///     }
+    /// })
+    /// .with_retryable();
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    pub fn new_retryable<F, P>(siv: &mut Cursive, mut factory: F) -> Self
+    where
+        E: From<String>,
+        F: FnMut() -> P + 'static,
+        P: FnMut() -> AsyncProgressState<T, E> + 'static,
+    {
+        Self::with_loader(siv, move |_, _| (PollMode::Fps, factory()))
+    }
+
+    fn with_loader<L, P>(siv: &mut Cursive, mut loader: L) -> Self
+    where
+        E: From<String>,
+        L: FnMut(&CbSink, &CancellationToken) -> (PollMode, P) + 'static,
+        P: FnMut() -> AsyncProgressState<T, E> + 'static,
+    {
+        let loader = move |sink: &CbSink, token: &CancellationToken| {
+            let (mode, creator) = loader(sink, token);
+            (mode, lifecycle::poll_fn(creator))
+        };
+        Self::with_lifecycle(Lifecycle::with_loader(siv, loader))
+    }
+
+    /// Create a new `AsyncProgressView` instance. The cursive reference is only used to
+    /// update the screen when a progress update is received. In order to show the view,
+    /// it has to be directly or indirectly added to a cursive layer like any other view.
+    ///
+    /// The `bg_task` function is executed on a background thread called
+    /// `cursive-async-view::bg_task`. It may report its progress with the given
+    /// `ProgressReporter`, which can be cloned and handed to further threads. The data
+    /// of type `D` it produces is converted to a view by the `view_creator` function.
+    ///
+    /// The reporter also tells whether the view still waits for the data, see
+    /// `ProgressReporter::is_cancelled`. The `bg_task` should check it regularly and
+    /// return early once the view has been cancelled or dropped.
+    ///
+    /// The view can be retried after `bg_task` failed, which runs `bg_task` again on a
+    /// new background thread with a fresh reporter. See `with_retryable` for details.
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// use cursive::views::TextView;
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive_async_view::AsyncProgressView;
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view: AsyncProgressView<TextView> = AsyncProgressView::new_with_bg_creator(&mut siv, |reporter| {
+    ///     for step in 1..=5 {
+    ///         // sleeps one second, but wakes up early when cancelled
+    ///         if reporter.wait_timeout(Duration::from_secs(1)) {
+    ///             return Err("Cancelled!".to_string());
+    ///         }
+    ///         reporter.set(step as f32 / 5.0);
+    ///     }
+    ///     Ok("Loaded in the background!")
+    /// }, TextView::new);
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    pub fn new_with_bg_creator<F, C, D>(siv: &mut Cursive, bg_task: F, view_creator: C) -> Self
+    where
+        E: From<String>,
+        D: Send + 'static,
+        F: FnMut(ProgressReporter) -> Result<D, E> + Send + 'static,
+        C: FnMut(D) -> T + 'static,
+    {
+        let bg_task = Arc::new(Mutex::new(bg_task));
+        let view_creator = Rc::new(RefCell::new(view_creator));
+
+        Self::with_loader(siv, move |sink, token| {
+            let (tx, rx) = unbounded();
+            let notifier = Notifier::new();
+            let reporter = ProgressReporter::new(notifier.clone(), token.clone());
+            let bg_task = Arc::clone(&bg_task);
+            let bg_reporter = reporter.clone();
+            let bg_notifier = notifier.clone();
+
+            thread::Builder::new()
+                .name("cursive-async-view::bg_task".into())
+                .spawn(move || {
+                    // a panic of the previous run does not keep the task from running again
+                    let mut bg_task = bg_task.lock().unwrap_or_else(PoisonError::into_inner);
+
+                    // the view may have been dropped already
+                    tx.send(unwind::catch(|| (*bg_task)(bg_reporter))).ok();
+                    bg_notifier.notify();
+                })
+                .unwrap();
+
+            let view_creator = Rc::clone(&view_creator);
+            let creator = move || match rx.try_recv() {
+                Ok(Ok(Ok(data))) => {
+                    AsyncProgressState::Available((*view_creator.borrow_mut())(data))
+                }
+                Ok(Ok(Err(err))) => AsyncProgressState::Error(err),
+                // resumed on the cursive thread, where the view handles it
+                Ok(Err(payload)) => panic::resume_unwind(payload),
+                Err(TryRecvError::Empty) => AsyncProgressState::Progress(reporter.progress()),
+                Err(TryRecvError::Disconnected) => AsyncProgressState::Error(E::from(
+                    "Internal error: bg_task disconnected unexpectedly!".to_string(),
+                )),
+            };
+
+            (PollMode::notified(sink, notifier), creator)
+        })
+    }
+
+    /// Create a new `AsyncProgressView` instance. The cursive reference is only used to
+    /// update the screen when a progress update is received. In order to show the view,
+    /// it has to be directly or indirectly added to a cursive layer like any other view.
+    ///
+    /// The future returned by `task` is spawned on the tokio runtime behind `handle`.
+    /// It may report its progress with the given `ProgressReporter`. The data of type `D`
+    /// the future resolves to is sent back to the cursive thread and converted to a view
+    /// by the `view_creator` function. A panic of the task is handled like any other
+    /// panic, see `with_panic_error`. The task is aborted once the view does not wait
+    /// for its result anymore, blocking sections of the task can check
+    /// `ProgressReporter::is_cancelled` to stop early. If the task is cancelled by
+    /// tokio, e.g. because the runtime shuts down, the cancel animation is shown.
+    ///
+    /// This constructor requires the `tokio` feature.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use cursive::views::TextView;
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive_async_view::AsyncProgressView;
+    ///
+    /// let runtime = tokio::runtime::Runtime::new().unwrap();
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view: AsyncProgressView<TextView> = AsyncProgressView::spawn_on(runtime.handle(), &mut siv, |reporter| async move {
+    ///     for step in 1..=5 {
+    ///         tokio::time::sleep(Duration::from_secs(1)).await;
+    ///         reporter.set(step as f32 / 5.0);
+    ///     }
+    ///     Ok("Spawned on tokio!")
+    /// }, TextView::new);
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub fn spawn_on<F, Fut, C, D>(
+        handle: &tokio::runtime::Handle,
+        siv: &mut Cursive,
+        task: F,
+        mut view_creator: C,
+    ) -> Self
+    where
+        E: From<String>,
+        D: Send + 'static,
+        F: FnOnce(ProgressReporter) -> Fut,
+        Fut: Future<Output = Result<D, E>> + Send + 'static,
+        C: FnMut(D) -> T + 'static,
+    {
+        let notifier = Notifier::new();
+        let token = CancellationToken::new();
+        let reporter = ProgressReporter::new(notifier.clone(), token.clone());
+        let task = AbortOnDrop(handle.spawn(task(reporter.clone())));
+        let mut task = LocalFuture::new(task, notifier.clone());
+
+        let mode = PollMode::notified(siv.cb_sink(), notifier);
+        Self::with_poll_mode(siv, mode, token, move || match task.poll() {
+            Poll::Ready(Ok(Ok(data))) => AsyncProgressState::Available(view_creator(data)),
+            Poll::Ready(Ok(Err(err))) => AsyncProgressState::Error(err),
+            // caught by the view like a panic of the poll function
+            Poll::Ready(Err(join_err)) if join_err.is_panic() => {
+                panic::resume_unwind(join_err.into_panic())
+            }
+            // e.g. the runtime has been shut down
+            Poll::Ready(Err(_)) => AsyncProgressState::Cancelled,
+            Poll::Pending => AsyncProgressState::Progress(reporter.progress()),
+        })
+    }
+
+    /// Create a new `AsyncProgressView` instance. The cursive reference is only used to
+    /// update the screen when a progress update is received. In order to show the view,
+    /// it has to be directly or indirectly added to a cursive layer like any other view.
+    ///
+    /// The `stream` is driven by a small built-in executor on the cursive thread, so no
+    /// particular async runtime is required. The progress bar always shows the latest
+    /// `ProgressEvent::Pending` or `ProgressEvent::Progress` item. Once the stream yields
+    /// `ProgressEvent::Available`, the contained data is converted to a view by the
+    /// `view_creator` function. A stream ending without data or an error shows an
+    /// error as well.
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// use cursive::views::TextView;
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive_async_view::{AsyncProgressView, ProgressEvent};
+    ///
+    /// let (tx, rx) = futures::channel::mpsc::unbounded();
+    /// thread::spawn(move || {
+    ///     for step in 1..=5 {
+    ///         thread::sleep(Duration::from_secs(1));
+    ///         tx.unbounded_send(ProgressEvent::Pending(step as f32 / 5.0)).unwrap();
+    ///     }
+    ///     tx.unbounded_send(ProgressEvent::Available("Streamed!")).unwrap();
+    /// });
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view: AsyncProgressView<TextView> = AsyncProgressView::from_stream(&mut siv, rx, TextView::new);
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    pub fn from_stream<S, C, D>(siv: &mut Cursive, stream: S, mut view_creator: C) -> Self
+    where
+        E: From<String>,
+        D: 'static,
+        S: Stream<Item = ProgressEvent<D, E>> + 'static,
+        C: FnMut(D) -> T + 'static,
+    {
+        let notifier = Notifier::new();
+        let mut stream = LocalStream::new(stream, notifier.clone());
+        let mut progress = Progress::default();
+
+        let mode = PollMode::notified(siv.cb_sink(), notifier);
+        Self::with_poll_mode(siv, mode, CancellationToken::new(), move || loop {
+            match stream.poll_next() {
+                Poll::Ready(Some(ProgressEvent::Pending(value))) => progress = Progress::new(value),
+                Poll::Ready(Some(ProgressEvent::Progress(next))) => progress = next,
+                Poll::Ready(Some(ProgressEvent::Available(data))) => {
+                    break AsyncProgressState::Available(view_creator(data))
+                }
+                Poll::Ready(Some(ProgressEvent::Error(err))) => {
+                    break AsyncProgressState::Error(err)
+                }
+                Poll::Ready(None) => {
+                    break AsyncProgressState::Error(E::from(
+                        "Internal error: progress stream ended without a result!".to_string(),
+                    ))
+                }
+                Poll::Pending => break AsyncProgressState::Progress(progress.clone()),
+            }
+        })
+    }
+
+    fn with_poll_mode<F>(
+        siv: &mut Cursive,
+        mode: PollMode,
+        token: CancellationToken,
+        creator: F,
+    ) -> Self
+    where
+        E: From<String>,
+        F: FnMut() -> AsyncProgressState<T, E> + 'static,
+    {
+        let creator = lifecycle::poll_fn(creator);
+        Self::with_lifecycle(Lifecycle::new(siv, mode, token, creator))
+    }
 
+    fn with_lifecycle(core: Lifecycle<T, E>) -> Self {
         Self {
-            view: AsyncProgressState::Pending(0.0),
-            loading: TextView::new(""),
+            core,
+            progress: Progress::default(),
             progress_fn: Box::new(default_progress),
             error_fn: Box::new(default_progress_error),
+            cancel_fn: Box::new(default_progress_cancel),
             width: None,
             height: None,
-            view_rx,
             frame_index: 0,
-            dropped: error_tx,
             pos: 0,
+            estimate: false,
+            estimator: Estimator::new(),
         }
     }
 
-    fn polling_cb<F>(
-        siv: &mut Cursive,
-        instant: Instant,
-        chan: SendWrapper<Sender<AsyncProgressState<T>>>,
-        error_chan: Receiver<()>,
-        mut cb: F,
-    ) where
-        F: FnMut() -> AsyncProgressState<T> + 'static,
+    /// The `CancellationToken` of this view. It is cancelled once the view does
+    /// not wait for its content anymore, e.g. because it has been dropped or the
+    /// content has been loaded. Hand it to your background tasks, so they can
+    /// stop working early.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.core.token()
+    }
+
+    /// Cancel loading the content of this view. This cancels the `CancellationToken`
+    /// of this view, stops polling for the content and shows the cancel animation.
+    /// Nothing happens if the content has already been loaded or failed to load.
+    ///
+    /// Cancelling a reload which keeps the previous content on screen restores the
+    /// previous content instead.
+    pub fn cancel(&mut self) {
+        if self.core.cancel() {
+            self.frame_index = 0;
+        }
+    }
+
+    /// Load the content of this view again with a new `loader`, which creates a
+    /// fresh `creator` function like the factory given to `new_retryable`. The view
+    /// moves back to the progress bar, a load still in progress is cancelled. By
+    /// default, the current child view is discarded, see `with_keep_on_reload` for
+    /// keeping it on screen until the replacement arrives.
+    ///
+    /// The view is usually reloaded from a callback, where it can be found by its
+    /// name:
+    ///
+    /// ```
+    /// use std::time::Instant;
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive::view::Nameable;
+    /// use cursive::views::TextView;
+    /// use cursive_async_view::{AsyncProgressView, AsyncProgressState};
+    ///
+    /// fn load(msg: &'static str) -> impl FnMut() -> AsyncProgressState<TextView> {
+    ///     let start = Instant::now();
+    ///     move || {
+    ///         if start.elapsed().as_secs() < 2 {
+    ///             AsyncProgressState::Pending(start.elapsed().as_secs() as f32 / 2f32)
+    ///         } else {
+    ///             AsyncProgressState::Available(TextView::new(msg))
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view = AsyncProgressView::new(&mut siv, load("Initial content"));
+    /// siv.add_layer(async_view.with_name("panel"));
+    ///
+    /// siv.add_global_callback('r', |siv| {
+    ///     let mut panel = siv.find_name::<AsyncProgressView<TextView>>("panel").unwrap();
+    ///     panel.reload(siv, || load("Refreshed content"));
+    /// });
+    /// // siv.run();
+    /// ```
+    ///
+    /// The `loader` replaces the loader of the view, so retrying the view afterwards
+    /// calls `loader` again. A view created with `new_with_notifier` keeps polling
+    /// only when its notifier has been notified.
+    pub fn reload<F, P>(&mut self, siv: &mut Cursive, mut loader: F)
+    where
+        F: FnMut() -> P + 'static,
+        P: FnMut() -> AsyncProgressState<T, E> + 'static,
     {
-        let res = cb();
-        match res {
-            AsyncProgressState::Pending(_) => {
-                let sink = siv.cb_sink().clone();
-                let cb = SendWrapper::new(cb);
-                match chan.send(res) {
-                    Ok(_) => {},
-                    Err(send_err) => warn!("Could not send progress to AsyncProgressView. It probably has been dropped before the asynchronous initialization of a view has been finished: {}", send_err),
-                }
-                thread::spawn(move || {
-                    // ensure ~60fps
-                    if let Some(duration) = FPS.checked_sub(instant.elapsed()) {
-                        thread::sleep(duration);
-                    }
-
-                    match sink.send(Box::new(move |siv| {
-                        Self::polling_cb(siv, Instant::now(), chan, error_chan, cb.take())
-                    })) {
-                        Ok(_) => {}
-                        Err(send_err) => {
-                            warn!("Could not send callback to cursive. It probably has been dropped before the asynchronous initialization of a view has been finished: {}", send_err);
-                        }
-                    }
-                });
-            }
-            AsyncProgressState::Error(content) => {
-                AsyncView::<T>::error_anim_cb(siv, error_chan);
-
-                match chan.send(AsyncProgressState::Error(content)) {
-                    Ok(_) => {}
-                    Err(send_err) => {
-                        warn!("View has been dropped before asynchronous initialization has been finished. Check if you removed this view from Cursive: {}", send_err);
-                    }
-                }
-                // chan dropped here, so the rx must handle disconnected
-            }
-            AsyncProgressState::Available(view) => {
-                match chan.send(AsyncProgressState::Available(view)) {
-                    Ok(_) => {}
-                    Err(send_err) => {
-                        warn!("View has been dropped before asynchronous initialization has been finished. Check if you removed this view from Cursive: {}", send_err);
-                    }
-                }
-            }
+        self.core.reload(siv, move || lifecycle::poll_fn(loader()));
+        self.restarted();
+        self.pos = 0;
+        self.frame_index = 0;
+    }
+
+    /// Keep the current child view on screen while the view is reloaded, until the
+    /// replacement has been loaded. If loading the replacement fails, the error
+    /// animation is shown instead.
+    pub fn with_keep_on_reload(mut self) -> Self {
+        self.set_keep_on_reload(true);
+        self
+    }
+
+    /// Keep the current child view on screen and interactive while the view is
+    /// reloaded, like `with_keep_on_reload`. The top row of the child view shows
+    /// the progress bar in the meantime. If loading the replacement fails, the
+    /// previous child view stays and a badge with the error is shown in its top
+    /// row instead, until the view is reloaded again.
+    pub fn with_stale_while_revalidate(mut self) -> Self {
+        self.set_stale_while_revalidate(true);
+        self
+    }
+
+    /// The error of the last reload, if it failed while the previous child view has
+    /// been kept on screen by `with_stale_while_revalidate`.
+    pub fn reload_error(&self) -> Option<&E> {
+        self.core.reload_error()
+    }
+
+    /// The error the content failed to load with, while the error animation is
+    /// shown. See `AsyncView::error` for an example.
+    pub fn error(&self) -> Option<&E> {
+        self.core.error()
+    }
+
+    /// Restart loading the content after it failed to load or has been cancelled.
+    /// Nothing happens if the view is still loading, has been loaded, or has not
+    /// been created with a retryable constructor like `new_retryable` or
+    /// `new_with_bg_creator`, and has not been reloaded either.
+    ///
+    /// A retry starts a new series of attempts of the `RetryPolicy`, if there is one.
+    pub fn retry(&mut self) {
+        if self.core.retry() {
+            self.restarted();
+            self.pos = 0;
+            self.frame_index = 0;
         }
     }
 
+    /// Allow the user to retry loading the content by pressing `r` or by clicking
+    /// on the error message. See `with_retry_event` for using a different key and
+    /// `retry` for which views can be retried.
+    pub fn with_retryable(mut self) -> Self {
+        self.set_retryable(true);
+        self
+    }
+
+    /// Allow the user to retry loading the content with the given event. See
+    /// `retry` for what retrying does. A custom error view gets the event first,
+    /// the view is only retried if the error view ignores it.
+    pub fn with_retry_event<Ev: Into<Event>>(mut self, event: Ev) -> Self {
+        self.set_retry_event(event);
+        self
+    }
+
+    /// Automatically retry loading the content after it failed, as long as the given
+    /// policy allows another attempt. Only the last error is shown. Like `retry`, this
+    /// requires the view to be created with a retryable constructor.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.set_retry_policy(policy);
+        self
+    }
+
+    /// Give up loading the content once `timeout` has passed since the creation of
+    /// this view, or since it has last been reloaded, refreshed or retried. The
+    /// automatic retries of a `RetryPolicy` do not restart the timeout, the waits
+    /// between them count towards it as well. On expiry the `CancellationToken` of this view is cancelled and the
+    /// error animation is shown with the message set by `with_timeout_msg`.
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    /// use cursive::{Cursive, CursiveExt};
+    /// use cursive::views::TextView;
+    /// use cursive_async_view::{AsyncProgressView, AsyncProgressState};
+    ///
+    /// let mut siv = Cursive::default();
+    /// let start = Instant::now();
+    /// let async_view: AsyncProgressView<TextView> = AsyncProgressView::new(&mut siv, move || {
+    ///     // takes 60 seconds, longer than we are willing to wait
+    ///     if start.elapsed().as_secs() < 60 {
+    ///         AsyncProgressState::Pending(start.elapsed().as_secs() as f32 / 60f32)
+    ///     } else {
+    ///         AsyncProgressState::Available(TextView::new("Finally!"))
+    ///     }
+    /// })
+    /// .with_timeout(Duration::from_secs(5))
+    /// .with_timeout_msg("The server did not answer in time")
+    /// .with_timeout_countdown();
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self
+    where
+        E: From<String>,
+    {
+        self.set_timeout(timeout);
+        self
+    }
+
+    /// Set the error message shown when loading the content timed out. Defaults to
+    /// `Loading timed out`.
+    pub fn with_timeout_msg<S: Into<String>>(mut self, msg: S) -> Self {
+        self.set_timeout_msg(msg);
+        self
+    }
+
+    /// Show the time remaining until the timeout below the progress bar.
+    pub fn with_timeout_countdown(mut self) -> Self {
+        self.set_timeout_countdown(true);
+        self
+    }
+
+    /// Estimate the rate of progress and the time left from the reported progress
+    /// values. The estimates are a moving average of the rates measured between two
+    /// reports, smoothed over a few seconds. They are handed to the progress function
+    /// with `Progress::rate` and `Progress::eta`, `default_progress` shows them below
+    /// the bar like `42% · 12s left`.
+    ///
+    /// ```
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// use cursive::{views::TextView, Cursive, CursiveExt};
+    /// use cursive_async_view::AsyncProgressView;
+    ///
+    /// let mut siv = Cursive::default();
+    /// let async_view: AsyncProgressView<TextView> = AsyncProgressView::new_with_bg_creator(&mut siv, |reporter| {
+    ///     for record in 1..=1000 {
+    ///         thread::sleep(Duration::from_millis(10));
+    ///         reporter.set(record as f32 / 1000.0);
+    ///     }
+    ///     Ok("Imported 1000 records")
+    /// }, TextView::new)
+    /// .with_estimate();
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    pub fn with_estimate(mut self) -> Self {
+        self.set_estimate(true);
+        self
+    }
+
+    /// Create the error shown for panics of the code loading the content with
+    /// `panic_error` instead of `E::from`. By default, panics are caught and shown
+    /// as an error carrying the panic message. See `AsyncView::with_panic_error` for
+    /// details.
+    pub fn with_panic_error(mut self, panic_error: fn(String) -> E) -> Self {
+        self.set_panic_error(panic_error);
+        self
+    }
+
+    /// Let panics of the code loading the content propagate into the cursive event
+    /// loop, which aborts the application, instead of showing them as an error.
+    pub fn with_abort_on_panic(mut self) -> Self {
+        self.set_abort_on_panic(true);
+        self
+    }
+
+    /// Allow the user to cancel loading the content by pressing `Esc`. See
+    /// `with_cancel_event` for using a different key.
+    pub fn with_cancellable(mut self) -> Self {
+        self.set_cancellable(true);
+        self
+    }
+
+    /// Allow the user to cancel loading the content with the given event. See
+    /// `cancel` for what cancelling does.
+    pub fn with_cancel_event<Ev: Into<Event>>(mut self, event: Ev) -> Self {
+        self.set_cancel_event(event);
+        self
+    }
+
+    /// Set a custom cancel function for this view, indicating that loading the wrapped
+    /// view has been cancelled. See the `default_progress_cancel` function reference for
+    /// an example on how to create a custom cancel function.
+    pub fn with_cancel_fn<F>(mut self, cancel_fn: F) -> Self
+    where
+        F: Fn(usize, usize, f32, usize, usize) -> AnimationProgressFrame + Send + Sync + 'static,
+    {
+        self.set_cancel_fn(cancel_fn);
+        self
+    }
+
     /// Mark the maximum allowed width in characters, the progress bar may consume.
     /// By default, the width will be inherited by the parent view.
     pub fn with_width(mut self, width: usize) -> Self {
@@ -389,7 +1198,10 @@ impl<T: View> AsyncProgressView<T> {
     /// example on how to create a custom progress function.
     pub fn with_progress_fn<F>(mut self, progress_fn: F) -> Self
     where
-        F: Fn(usize, usize, f32, usize, usize) -> AnimationProgressFrame + Send + Sync + 'static,
+        F: Fn(usize, usize, &Progress, usize, usize) -> AnimationProgressFrame
+            + Send
+            + Sync
+            + 'static,
     {
         self.set_progress_fn(progress_fn);
         self
@@ -397,7 +1209,7 @@ impl<T: View> AsyncProgressView<T> {
 
     pub fn with_error_fn<F>(mut self, error_fn: F) -> Self
     where
-        F: Fn(String, usize, usize, f32, usize, usize) -> AnimationProgressFrame
+        F: Fn(&E, usize, usize, f32, usize, usize) -> AnimationProgressFrame
             + Send
             + Sync
             + 'static,
@@ -406,6 +1218,41 @@ impl<T: View> AsyncProgressView<T> {
         self
     }
 
+    /// Show the view created by `error_view` instead of the error animation, once
+    /// the content failed to load. The view is created from the error on the
+    /// cursive thread and replaced by the progress bar again on a retry. See
+    /// `AsyncView::with_error_view` for an example.
+    pub fn with_error_view<F, V>(mut self, error_view: F) -> Self
+    where
+        F: Fn(&E) -> V + Send + Sync + 'static,
+        V: View,
+    {
+        self.set_error_view(error_view);
+        self
+    }
+
+    /// Show the child view created by `fallback` instead of an error, once the
+    /// content failed to load. The error is handed to the callback set by
+    /// `with_fallback_callback`, if any. See `AsyncView::with_fallback` for
+    /// details and an example.
+    pub fn with_fallback<F>(mut self, fallback: F) -> Self
+    where
+        F: Fn(&E) -> T + Send + Sync + 'static,
+    {
+        self.set_fallback(fallback);
+        self
+    }
+
+    /// Call `callback` on the cursive thread with every error replaced by the fallback.
+    /// See `with_fallback` for details.
+    pub fn with_fallback_callback<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&mut Cursive, E) + Send + Sync + 'static,
+    {
+        self.set_fallback_callback(callback);
+        self
+    }
+
     /// Set the maximum allowed width in characters, the progress bar may consume.
     pub fn set_width(&mut self, width: usize) {
         self.width = Some(width);
@@ -424,7 +1271,10 @@ impl<T: View> AsyncProgressView<T> {
     /// the previous progress bar has already be drawn.
     pub fn set_progress_fn<F>(&mut self, progress_fn: F)
     where
-        F: Fn(usize, usize, f32, usize, usize) -> AnimationProgressFrame + Send + Sync + 'static,
+        F: Fn(usize, usize, &Progress, usize, usize) -> AnimationProgressFrame
+            + Send
+            + Sync
+            + 'static,
     {
         self.progress_fn = Box::new(progress_fn);
     }
@@ -437,7 +1287,7 @@ impl<T: View> AsyncProgressView<T> {
     /// the previous progress bar has already be drawn.
     pub fn set_error_fn<F>(&mut self, error_fn: F)
     where
-        F: Fn(String, usize, usize, f32, usize, usize) -> AnimationProgressFrame
+        F: Fn(&E, usize, usize, f32, usize, usize) -> AnimationProgressFrame
             + Send
             + Sync
             + 'static,
@@ -445,6 +1295,153 @@ impl<T: View> AsyncProgressView<T> {
         self.error_fn = Box::new(error_fn);
     }
 
+    /// Set a function creating the view shown instead of the error animation. See
+    /// `with_error_view` for details.
+    ///
+    /// This function may be set at any time, but does not replace an error view which
+    /// has already been created.
+    pub fn set_error_view<F, V>(&mut self, error_view: F)
+    where
+        F: Fn(&E) -> V + Send + Sync + 'static,
+        V: View,
+    {
+        self.core.error_view_fn = Some(Box::new(move |err| Box::new(error_view(err))));
+    }
+
+    /// Show the error animation once the content failed to load. This is the default.
+    pub fn unset_error_view(&mut self) {
+        self.core.unset_error_view();
+    }
+
+    /// Set a function creating the child view shown instead of an error. See
+    /// `with_fallback` for details.
+    pub fn set_fallback<F>(&mut self, fallback: F)
+    where
+        F: Fn(&E) -> T + Send + Sync + 'static,
+    {
+        self.core.fallback_fn = Some(Box::new(fallback));
+    }
+
+    /// Show errors instead of a fallback child view. This is the default.
+    pub fn unset_fallback(&mut self) {
+        self.core.fallback_fn = None;
+    }
+
+    /// Set the callback receiving errors replaced by the fallback. See
+    /// `with_fallback` for details.
+    pub fn set_fallback_callback<F>(&mut self, callback: F)
+    where
+        F: Fn(&mut Cursive, E) + Send + Sync + 'static,
+    {
+        self.core.fallback_callback = Some(Arc::new(callback));
+    }
+
+    /// Set the duration after which loading the content is given up, measured from
+    /// the creation, the last reload, refresh or retry of this view. See
+    /// `with_timeout` for details.
+    pub fn set_timeout(&mut self, timeout: Duration)
+    where
+        E: From<String>,
+    {
+        self.core.set_timeout(timeout, E::from);
+    }
+
+    /// Wait for the content without a timeout. This is the default.
+    pub fn unset_timeout(&mut self) {
+        self.core.timeout = None;
+    }
+
+    /// Set the error message shown when loading the content timed out.
+    pub fn set_timeout_msg<S: Into<String>>(&mut self, msg: S) {
+        self.core.timeout_msg = msg.into();
+    }
+
+    /// Set whether the time remaining until the timeout is shown below the progress
+    /// bar. The countdown is hidden by default.
+    pub fn set_timeout_countdown(&mut self, countdown: bool) {
+        self.core.timeout_countdown = countdown;
+    }
+
+    /// Set whether the rate of progress and the time left are estimated and shown.
+    /// Estimates are disabled by default, see `with_estimate` for details.
+    pub fn set_estimate(&mut self, estimate: bool) {
+        self.estimate = estimate;
+    }
+
+    /// Set the function creating the error shown for panics of the code loading the
+    /// content. See `with_panic_error` for details.
+    pub fn set_panic_error(&mut self, panic_error: fn(String) -> E) {
+        self.core.panic_error = panic_error;
+    }
+
+    /// Set whether panics of the code loading the content propagate into the
+    /// cursive event loop instead of being shown as an error. See
+    /// `with_abort_on_panic` for details.
+    pub fn set_abort_on_panic(&mut self, abort: bool) {
+        self.core.abort_on_panic = abort;
+    }
+
+    /// Set whether the user may retry loading the content by pressing `r` or by
+    /// clicking on the error message. Retrying is disabled by default.
+    pub fn set_retryable(&mut self, retryable: bool) {
+        self.core.set_retryable(retryable);
+    }
+
+    /// Set the event which retries loading the content, while the error or cancel
+    /// animation is shown. The view takes the focus to receive the event.
+    pub fn set_retry_event<Ev: Into<Event>>(&mut self, event: Ev) {
+        self.core.retry_event = Some(event.into());
+    }
+
+    /// Set whether the current child view stays on screen while the view is
+    /// reloaded. The child view is discarded by default.
+    pub fn set_keep_on_reload(&mut self, keep: bool) {
+        self.core.keep_on_reload = keep;
+    }
+
+    /// Set whether the current child view stays on screen with an overlay while the
+    /// view is reloaded. See `with_stale_while_revalidate` for details. Enabling this
+    /// also keeps the child view on reload.
+    pub fn set_stale_while_revalidate(&mut self, revalidate: bool) {
+        self.core.set_stale_while_revalidate(revalidate);
+    }
+
+    /// Set the policy to automatically retry loading the content after it failed.
+    /// See `with_retry_policy` for details.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.core.retry_policy = Some(policy);
+    }
+
+    /// Do not retry loading the content automatically. This is the default.
+    pub fn unset_retry_policy(&mut self) {
+        self.core.retry_policy = None;
+    }
+
+    /// Set whether the user may cancel loading the content by pressing `Esc`.
+    /// Cancelling is disabled by default.
+    pub fn set_cancellable(&mut self, cancellable: bool) {
+        self.core.set_cancellable(cancellable);
+    }
+
+    /// Set the event which cancels loading the content, while the progress bar
+    /// is shown. The view takes the focus to receive the event.
+    pub fn set_cancel_event<Ev: Into<Event>>(&mut self, event: Ev) {
+        self.core.cancel_event = Some(event.into());
+    }
+
+    /// Set a custom cancel function for this view, indicating that loading the wrapped
+    /// view has been cancelled. See the `default_progress_cancel` function reference for
+    /// an example on how to create a custom cancel function.
+    ///
+    /// The function may be set at any time. The cancel animation can be changed even if
+    /// the previous cancel animation has already be drawn.
+    pub fn set_cancel_fn<F>(&mut self, cancel_fn: F)
+    where
+        F: Fn(usize, usize, f32, usize, usize) -> AnimationProgressFrame + Send + Sync + 'static,
+    {
+        self.cancel_fn = Box::new(cancel_fn);
+    }
+
     /// Make the progress bar inherit its width from the parent view. This is the default.
     pub fn inherit_width(&mut self) {
         self.width = None;
@@ -454,143 +1451,155 @@ impl<T: View> AsyncProgressView<T> {
     pub fn inherit_height(&mut self) {
         self.height = None;
     }
-}
 
-impl<T: View> Drop for AsyncProgressView<T> {
-    fn drop(&mut self) {
-        match self.dropped.send(()) {
-            Ok(_) => {}
-            Err(send_err) => warn!(
-                "Refreshing thread has been dropped before view has, this has no impact on your code and is a bug: {}",
-                send_err
-            ),
+    /// Show `progress` from now on. Switching between an indeterminate and a
+    /// determinate progress restarts the animation, so the progress function hands
+    /// over smoothly instead of continuing in the middle of an animation.
+    fn set_progress(&mut self, progress: Progress) {
+        if progress.is_indeterminate() != self.progress.is_indeterminate() {
+            self.frame_index = 0;
+            self.pos = 0;
         }
+        self.progress = progress;
+    }
+
+    /// Forget the progress of the previous attempt, as a new one has been started.
+    fn restarted(&mut self) {
+        self.progress = Progress::default();
+        self.estimator = Estimator::new();
     }
 }
 
-impl<T: View + Sized> View for AsyncProgressView<T> {
+impl<T: View + Sized, E: Display + Send + Sync + 'static> View for AsyncProgressView<T, E> {
     fn draw(&self, printer: &Printer) {
-        match &self.view {
-            AsyncProgressState::Available(v) => {
-                v.draw(printer);
-            }
-            AsyncProgressState::Error(_) | AsyncProgressState::Pending(_) => {
-                self.loading.draw(printer)
-            }
-        }
+        self.core.draw(printer);
     }
 
     fn layout(&mut self, vec: Vec2) {
-        match &mut self.view {
-            AsyncProgressState::Available(v) => v.layout(vec),
-            AsyncProgressState::Error(_) | AsyncProgressState::Pending(_) => {
-                self.loading.layout(vec)
-            }
-        }
+        self.core.layout(vec);
     }
 
     fn needs_relayout(&self) -> bool {
-        match &self.view {
-            AsyncProgressState::Available(v) => v.needs_relayout(),
-            AsyncProgressState::Error(_) | AsyncProgressState::Pending(_) => {
-                self.loading.needs_relayout()
-            }
-        }
+        self.core.needs_relayout()
     }
 
     fn required_size(&mut self, constraint: Vec2) -> Vec2 {
-        if !matches!(self.view, AsyncProgressState::Available(_)) {
-            if let Ok(state) = self.view_rx.try_recv() {
-                self.view = state
-            }
+        match self.core.update() {
+            Change::Progress(progress) => self.set_progress(progress),
+            Change::Restarted => self.restarted(),
+            // the error animation starts from its first frame
+            Change::Failed => self.frame_index = 0,
+            Change::None => {}
         }
 
-        match &mut self.view {
-            AsyncProgressState::Available(v) => v.required_size(constraint),
-            AsyncProgressState::Pending(value) => {
+        if self.estimate && self.core.is_pending() && !self.progress.is_indeterminate() {
+            let now = Instant::now();
+            self.estimator.update(self.progress.value(), now);
+            self.progress
+                .set_estimate(self.estimator.rate(), self.estimator.eta(now));
+        }
+
+        if let Some(v) = self.core.error_view_mut() {
+            return v.required_size(constraint);
+        }
+
+        match &mut self.core.phase {
+            Phase::Available(v) => v.required_size(constraint),
+            Phase::Pending if self.core.stale.is_some() => {
+                if self.core.revalidate {
+                    let width = self.width.unwrap_or(constraint.x);
+                    let AnimationProgressFrame {
+                        content,
+                        pos,
+                        next_frame_idx,
+                    } = (self.progress_fn)(width, 1, &self.progress, self.pos, self.frame_index);
+                    self.pos = pos;
+                    self.frame_index = next_frame_idx;
+                    self.core.overlay = content;
+                }
+
+                // the previous content stays until the new one has been loaded
+                self.core.stale.as_mut().unwrap().required_size(constraint)
+            }
+            Phase::Pending => {
+                let width = self.width.unwrap_or(constraint.x);
+                let height = self.height.unwrap_or(constraint.y);
+                let AnimationProgressFrame {
+                    content,
+                    pos,
+                    next_frame_idx,
+                } = (self.progress_fn)(width, height, &self.progress, self.pos, self.frame_index);
+                self.pos = pos;
+                self.frame_index = next_frame_idx;
+                self.core.loading.set_content(content);
+                if let Some(line) = self.core.status_line(width) {
+                    self.core.loading.append(line);
+                }
+
+                self.core.loading.required_size(constraint)
+            }
+            Phase::Error(err) => {
                 let width = self.width.unwrap_or(constraint.x);
                 let height = self.height.unwrap_or(constraint.y);
                 let AnimationProgressFrame {
                     content,
                     pos,
                     next_frame_idx,
-                } = (self.progress_fn)(
-                    width,
-                    height,
-                    clamp(*value, 0.0, 1.0),
-                    self.pos,
-                    self.frame_index,
-                );
+                } = (self.error_fn)(err, width, height, 0.5, self.pos, self.frame_index);
+                let done = next_frame_idx == self.frame_index;
                 self.pos = pos;
                 self.frame_index = next_frame_idx;
-                self.loading.set_content(content);
-                self.loading.required_size(constraint)
+                let details = self
+                    .core
+                    .details
+                    .get_or_insert_with(|| ErrorDetails::new(err.to_string()));
+                details.set_frame(content, width, done);
+                // the details scroll within the size set by `with_width` and `with_height`
+                details.required_size(Vec2::min(constraint, (width, height)))
             }
-            AsyncProgressState::Error(msg) => {
+            Phase::Cancelled => {
                 let width = self.width.unwrap_or(constraint.x);
                 let height = self.height.unwrap_or(constraint.y);
                 let AnimationProgressFrame {
                     content,
                     pos,
                     next_frame_idx,
-                } = (self.error_fn)(
-                    (*msg).to_string(),
-                    width,
-                    height,
-                    0.5,
-                    self.pos,
-                    self.frame_index,
-                );
+                } = (self.cancel_fn)(width, height, 0.5, self.pos, self.frame_index);
                 self.pos = pos;
                 self.frame_index = next_frame_idx;
-                self.loading.set_content(content);
-                self.loading.required_size(constraint)
+                self.core.loading.set_content(content);
+                self.core.loading.required_size(constraint)
             }
         }
     }
 
     fn on_event(&mut self, ev: Event) -> EventResult {
-        match &mut self.view {
-            AsyncProgressState::Available(v) => v.on_event(ev),
-            AsyncProgressState::Error(_) | AsyncProgressState::Pending(_) => {
-                self.loading.on_event(ev)
+        match self.core.on_event(ev) {
+            Handled::Event(result) => result,
+            Handled::Retry => {
+                self.retry();
+                EventResult::Consumed(None)
+            }
+            Handled::Cancel => {
+                self.cancel();
+                EventResult::Consumed(None)
             }
         }
     }
 
     fn call_on_any<'a>(&mut self, sel: &Selector, cb: AnyCb<'a>) {
-        match &mut self.view {
-            AsyncProgressState::Available(v) => v.call_on_any(sel, cb),
-            AsyncProgressState::Error(_) | AsyncProgressState::Pending(_) => {
-                self.loading.call_on_any(sel, cb)
-            }
-        }
+        self.core.call_on_any(sel, cb);
     }
 
     fn focus_view(&mut self, sel: &Selector) -> Result<EventResult, ViewNotFound> {
-        match &mut self.view {
-            AsyncProgressState::Available(v) => v.focus_view(sel),
-            AsyncProgressState::Error(_) | AsyncProgressState::Pending(_) => {
-                self.loading.focus_view(sel)
-            }
-        }
+        self.core.focus_view(sel)
     }
 
     fn take_focus(&mut self, source: Direction) -> Result<EventResult, CannotFocus> {
-        match &mut self.view {
-            AsyncProgressState::Available(v) => v.take_focus(source),
-            AsyncProgressState::Error(_) | AsyncProgressState::Pending(_) => {
-                self.loading.take_focus(source)
-            }
-        }
+        self.core.take_focus(source)
     }
 
     fn important_area(&self, view_size: Vec2) -> Rect {
-        match &self.view {
-            AsyncProgressState::Available(v) => v.important_area(view_size),
-            AsyncProgressState::Error(_) | AsyncProgressState::Pending(_) => {
-                self.loading.important_area(view_size)
-            }
-        }
+        self.core.important_area(view_size)
     }
 }
@@ -0,0 +1,162 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::cancel::CancellationToken;
+use crate::notify::Notifier;
+use crate::status::{Progress, Unit};
+
+/// A handle to report the progress of a background task to an
+/// `AsyncProgressView`. The reporter can be cloned and used from any thread,
+/// the view always shows the latest reported value and status message.
+///
+/// Tasks made of several child tasks hand a reporter created with `add_child` to
+/// each of them. The progress of the children is aggregated into the main bar,
+/// see `Progress::with_weighted_child`.
+///
+/// The reporter carries the `CancellationToken` of the view, so tasks can stop
+/// early once the view does not wait for them anymore, see `is_cancelled`.
+///
+/// ```
+/// use std::thread;
+///
+/// use cursive::{views::TextView, Cursive, CursiveExt};
+/// use cursive_async_view::AsyncProgressView;
+///
+/// let mut siv = Cursive::default();
+/// let async_view: AsyncProgressView<TextView> = AsyncProgressView::new_with_bg_creator(&mut siv, |reporter| {
+///     // the children report their progress from their own threads
+///     let workers = ["serde", "tokio", "cursive"]
+///         .iter()
+///         .map(|name| {
+///             let child = reporter.add_child(1.0);
+///             child.set_message(*name);
+///             thread::spawn(move || {
+///                 for step in 1..=10 {
+///                     child.set(step as f32 / 10.0);
+///                 }
+///             })
+///         })
+///         .collect::<Vec<_>>();
+///
+///     for worker in workers {
+///         worker.join().unwrap();
+///     }
+///     Ok("Built all crates")
+/// }, TextView::new);
+///
+/// siv.add_layer(async_view);
+/// // siv.run();
+/// ```
+#[derive(Clone)]
+pub struct ProgressReporter {
+    progress: Arc<Mutex<Progress>>,
+    // the child indices leading from the root to the node of this reporter
+    path: Vec<usize>,
+    notifier: Notifier,
+    token: CancellationToken,
+}
+
+impl ProgressReporter {
+    pub(crate) fn new(notifier: Notifier, token: CancellationToken) -> Self {
+        Self {
+            progress: Arc::new(Mutex::new(Progress::default())),
+            path: Vec::new(),
+            notifier,
+            token,
+        }
+    }
+
+    /// Report the progress made as float value between 0 and 1. Values out
+    /// of this range are clamped when drawn.
+    pub fn set(&self, progress: f32) {
+        self.update(|node| node.set_value(progress));
+    }
+
+    /// Report that the progress made cannot be told for now, e.g. while connecting
+    /// to a server. The view shows an animation until a value or an amount with a
+    /// known total is reported, see `Progress::indeterminate`.
+    pub fn set_indeterminate(&self) {
+        self.update(Progress::set_indeterminate);
+    }
+
+    /// Report the `current` amount done of a `total` amount in `unit`. The progress
+    /// is indeterminate while the total is unknown, see `Progress::amount`.
+    pub fn set_amount(&self, current: u64, total: Option<u64>, unit: Unit) {
+        self.update(|node| node.set_amount(current, total, unit));
+    }
+
+    /// Report a status message describing what the task is currently doing. The
+    /// message is shown until it is replaced or removed with `unset_message`.
+    pub fn set_message<S: Into<String>>(&self, message: S) {
+        self.update(|node| node.set_message(message));
+    }
+
+    /// Remove the reported status message.
+    pub fn unset_message(&self) {
+        self.update(Progress::unset_message);
+    }
+
+    /// Add a child task, which counts `weight` times towards the progress of this
+    /// reporter. The returned reporter reports the progress of the child and may
+    /// add children of its own. See `Progress::with_weighted_child` for details.
+    pub fn add_child(&self, weight: f32) -> ProgressReporter {
+        let idx = self.update(|node| {
+            node.add_weighted_child(weight, Progress::default());
+            node.child_count() - 1
+        });
+
+        let mut path = self.path.clone();
+        path.push(idx);
+        Self {
+            progress: Arc::clone(&self.progress),
+            path,
+            notifier: self.notifier.clone(),
+            token: self.token.clone(),
+        }
+    }
+
+    /// The latest reported progress.
+    pub fn get(&self) -> f32 {
+        self.with_node(|node| node.value())
+    }
+
+    /// Whether the view does not wait for the task anymore, e.g. because it has
+    /// been cancelled or dropped. The task should return early in this case.
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Block the current thread until the view gets cancelled or `timeout` has
+    /// elapsed. Returns whether the view has been cancelled, see
+    /// `CancellationToken::wait_timeout`.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        self.token.wait_timeout(timeout)
+    }
+
+    /// The `CancellationToken` of the view, e.g. to hand it to code which does
+    /// not report progress.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+
+    // the latest reported progress of all tasks together with their status messages
+    pub(crate) fn progress(&self) -> Progress {
+        self.progress.lock().unwrap().clone()
+    }
+
+    // change the node of this reporter and wake the view
+    fn update<R>(&self, f: impl FnOnce(&mut Progress) -> R) -> R {
+        let result = self.with_node(f);
+        self.notifier.notify();
+        result
+    }
+
+    fn with_node<R>(&self, f: impl FnOnce(&mut Progress) -> R) -> R {
+        let mut root = self.progress.lock().unwrap();
+        let node = self
+            .path
+            .iter()
+            .fold(&mut *root, |node, idx| node.child_mut(*idx));
+        f(node)
+    }
+}
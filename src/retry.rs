@@ -0,0 +1,138 @@
+use std::collections::hash_map::RandomState;
+use std::convert::TryFrom;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// A policy to automatically retry loading the content of a retryable
+/// `AsyncView` or `AsyncProgressView` after it failed.
+///
+/// Between two attempts the view waits for a delay, which starts at the initial
+/// delay and is multiplied by the multiplier after every failed attempt. The
+/// jitter spreads the delays randomly, so many views failing at once do not
+/// retry all at the same time. While waiting, the loading animation shows when
+/// the next attempt starts. The error animation is only shown once all attempts
+/// have failed.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use cursive::views::TextView;
+/// use cursive::{Cursive, CursiveExt};
+/// use cursive_async_view::{AsyncView, RetryPolicy};
+///
+/// let mut siv = Cursive::default();
+/// let async_view = AsyncView::new_retryable_with_bg_creator(&mut siv, move |_token| {
+///     std::thread::sleep(Duration::from_secs(1));
+///     Err::<String, _>("Server unreachable".to_string())
+/// }, TextView::new)
+/// .with_retry_policy(
+///     RetryPolicy::new(5)
+///         .with_initial_delay(Duration::from_millis(500))
+///         .with_multiplier(2.0)
+///         .with_jitter(0.1),
+/// );
+///
+/// siv.add_layer(async_view);
+/// // siv.run();
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: usize,
+    initial_delay: Duration,
+    multiplier: f64,
+    jitter: f64,
+}
+
+impl RetryPolicy {
+    /// Create a new policy which tries to load the content at most `max_attempts`
+    /// times, including the first attempt. By default, the initial delay is one
+    /// second, the multiplier is 2 and there is no jitter.
+    pub fn new(max_attempts: usize) -> Self {
+        Self {
+            max_attempts,
+            initial_delay: Duration::from_secs(1),
+            multiplier: 2.0,
+            jitter: 0.0,
+        }
+    }
+
+    /// Set how often the content is tried to be loaded, including the first attempt.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.set_max_attempts(max_attempts);
+        self
+    }
+
+    /// Set the delay before the second attempt.
+    pub fn with_initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.set_initial_delay(initial_delay);
+        self
+    }
+
+    /// Set the factor the delay is multiplied with after every failed attempt.
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.set_multiplier(multiplier);
+        self
+    }
+
+    /// Set the jitter as fraction of the delay. A jitter of `0.1` randomly
+    /// shortens or lengthens every delay by up to 10%.
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.set_jitter(jitter);
+        self
+    }
+
+    /// Set how often the content is tried to be loaded, including the first attempt.
+    pub fn set_max_attempts(&mut self, max_attempts: usize) {
+        self.max_attempts = max_attempts;
+    }
+
+    /// Set the delay before the second attempt.
+    pub fn set_initial_delay(&mut self, initial_delay: Duration) {
+        self.initial_delay = initial_delay;
+    }
+
+    /// Set the factor the delay is multiplied with after every failed attempt.
+    pub fn set_multiplier(&mut self, multiplier: f64) {
+        self.multiplier = multiplier;
+    }
+
+    /// Set the jitter as fraction of the delay, between 0 and 1.
+    pub fn set_jitter(&mut self, jitter: f64) {
+        self.jitter = jitter;
+    }
+
+    /// How often the content is tried to be loaded, including the first attempt.
+    pub fn max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// The delay before the next attempt after `attempt` attempts have failed,
+    /// or `None` if no attempts are left.
+    pub fn delay(&self, attempt: usize) -> Option<Duration> {
+        if attempt == 0 || attempt >= self.max_attempts {
+            return None;
+        }
+
+        let exponent = i32::try_from(attempt - 1).unwrap_or(i32::MAX);
+        let delay = self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent);
+
+        let jitter = num::clamp(self.jitter, 0.0, 1.0);
+        let factor = 1.0 + jitter * (2.0 * random_unit() - 1.0);
+
+        // overflowing delays wait forever
+        Some(Duration::try_from_secs_f64((delay * factor).max(0.0)).unwrap_or(Duration::MAX))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+/// A random number between 0 and 1. This is good enough to spread retries, as
+/// every `RandomState` is seeded randomly.
+fn random_unit() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,387 @@
+use std::time::Duration;
+
+use num::clamp;
+
+/// The progress made by the creation of an `AsyncProgressView`, as reported with
+/// `AsyncProgressState::Progress` and handed to progress functions.
+///
+/// Besides the progress value, it may carry a status message describing what is
+/// currently being done, e.g. `Resolving dependencies…` or `Downloading 3/12`. If
+/// enabled with `AsyncProgressView::with_estimate`, the view also estimates the rate
+/// of progress and the time left before handing the progress to the progress function.
+///
+/// ```
+/// use cursive_async_view::Progress;
+///
+/// let progress = Progress::new(0.25).with_message("Downloading 3/12");
+/// assert_eq!(progress.value(), 0.25);
+/// assert_eq!(progress.message(), Some("Downloading 3/12"));
+///
+/// // the value is always between 0 and 1
+/// assert_eq!(Progress::new(1.5).value(), 1.0);
+/// ```
+///
+/// The progress may also be reported as an amount of some `Unit`, the value is
+/// derived from it. If the total amount is unknown, the progress is indeterminate and
+/// `default_progress` shows an animation instead of the bar.
+///
+/// ```
+/// use cursive_async_view::{Progress, Unit};
+///
+/// let progress = Progress::amount(1204, Some(5000), Unit::items("rows"));
+/// assert_eq!(progress.value(), 0.2408);
+/// assert_eq!(progress.format_amount().unwrap(), "1,204 / 5,000 rows");
+///
+/// let progress = Progress::amount(35_861_300, Some(120 * 1024 * 1024), Unit::Bytes);
+/// assert_eq!(progress.format_amount().unwrap(), "34.2 MiB / 120 MiB");
+///
+/// let progress = Progress::amount(35_861_300, None, Unit::Bytes);
+/// assert!(progress.is_indeterminate());
+/// assert_eq!(progress.format_amount().unwrap(), "34.2 MiB");
+/// ```
+///
+/// A task made of several child tasks reports them as a tree of progress nodes. The
+/// value of a node with children is their weighted average, `default_progress` shows
+/// the active children as indented bars below the main bar if there is enough room.
+///
+/// ```
+/// use cursive_async_view::Progress;
+///
+/// let progress = Progress::default()
+///     .with_child(Progress::new(1.0).with_message("fetch"))
+///     .with_weighted_child(2.0, Progress::new(0.25).with_message("compile"))
+///     .with_child(Progress::new(0.0).with_message("link"));
+///
+/// // (1.0 + 2.0 * 0.25 + 0.0) / 4.0
+/// assert_eq!(progress.value(), 0.375);
+///
+/// let active = progress
+///     .children()
+///     .filter(|(_, child)| child.is_active())
+///     .map(|(_, child)| child.message().unwrap())
+///     .collect::<Vec<_>>();
+/// assert_eq!(active, ["compile"]);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Progress {
+    // `None` if the progress cannot be told
+    value: Option<f32>,
+    amount: Option<Amount>,
+    message: Option<String>,
+    rate: Option<f32>,
+    eta: Option<Duration>,
+    children: Vec<(f32, Progress)>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Amount {
+    current: u64,
+    total: Option<u64>,
+    unit: Unit,
+}
+
+/// The unit of the amounts reported with `Progress::amount`, used to format them
+/// in a human-readable way.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Unit {
+    /// Bytes, formatted with binary prefixes like `34.2 MiB`.
+    Bytes,
+    /// Countable items like rows or files, formatted with thousands separators and
+    /// the given name like `1,204 rows`.
+    Items(String),
+}
+
+impl Progress {
+    /// Create a new `Progress` from the progress made as float value between 0 and 1.
+    pub fn new(value: f32) -> Self {
+        Self {
+            value: Some(value),
+            amount: None,
+            message: None,
+            rate: None,
+            eta: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Create a new `Progress` for a phase of unknown length, like connecting to a
+    /// server. `default_progress` shows an animation like `default_animation` until
+    /// the progress can be told, then the bar grows smoothly from the start.
+    ///
+    /// ```
+    /// use cursive::{views::TextView, Cursive, CursiveExt};
+    /// use cursive_async_view::{AsyncProgressView, AsyncProgressState, Progress};
+    ///
+    /// let mut siv = Cursive::default();
+    /// let start = std::time::Instant::now();
+    /// let async_view: AsyncProgressView<TextView> = AsyncProgressView::new(&mut siv, move || {
+    ///     let elapsed = start.elapsed().as_secs_f32();
+    ///     if elapsed < 2.0 {
+    ///         // connecting takes as long as it takes
+    ///         AsyncProgressState::Progress(Progress::indeterminate().with_message("Connecting…"))
+    ///     } else if elapsed < 5.0 {
+    ///         AsyncProgressState::Pending((elapsed - 2.0) / 3.0)
+    ///     } else {
+    ///         AsyncProgressState::Available(TextView::new("Connected and loaded!"))
+    ///     }
+    /// });
+    ///
+    /// assert!(Progress::indeterminate().is_indeterminate());
+    /// assert!(!Progress::new(0.0).is_indeterminate());
+    ///
+    /// siv.add_layer(async_view);
+    /// // siv.run();
+    /// ```
+    pub fn indeterminate() -> Self {
+        let mut progress = Self::default();
+        progress.set_indeterminate();
+        progress
+    }
+
+    /// Create a new `Progress` from the `current` amount done of a `total` amount
+    /// in `unit`. The value is the fraction of both, an unknown `total` makes the
+    /// progress indeterminate.
+    pub fn amount(current: u64, total: Option<u64>, unit: Unit) -> Self {
+        let mut progress = Self::default();
+        progress.set_amount(current, total, unit);
+        progress
+    }
+
+    /// Attach a status message to this progress.
+    pub fn with_message<S: Into<String>>(mut self, message: S) -> Self {
+        self.set_message(message);
+        self
+    }
+
+    /// Add a child task with a weight of 1, see `with_weighted_child`.
+    pub fn with_child(mut self, child: Progress) -> Self {
+        self.add_child(child);
+        self
+    }
+
+    /// Add a child task, which counts `weight` times towards the value of this
+    /// progress. Once there are children, the value of this progress is their
+    /// weighted average instead of the value set on it.
+    pub fn with_weighted_child(mut self, weight: f32, child: Progress) -> Self {
+        self.add_weighted_child(weight, child);
+        self
+    }
+
+    /// Add a child task with a weight of 1, see `with_weighted_child`.
+    pub fn add_child(&mut self, child: Progress) {
+        self.add_weighted_child(1.0, child);
+    }
+
+    /// Add a child task, which counts `weight` times towards the value of this
+    /// progress, see `with_weighted_child`.
+    pub fn add_weighted_child(&mut self, weight: f32, child: Progress) {
+        self.children.push((weight.max(0.0), child));
+    }
+
+    /// Set the progress made as float value between 0 and 1. This replaces an
+    /// amount set before.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = Some(value);
+        self.amount = None;
+    }
+
+    /// Mark the progress made as unknown, see `indeterminate`. This replaces a value
+    /// or an amount set before.
+    pub fn set_indeterminate(&mut self) {
+        self.value = None;
+        self.amount = None;
+    }
+
+    /// Set the `current` amount done of a `total` amount in `unit`, see `amount`.
+    pub fn set_amount(&mut self, current: u64, total: Option<u64>, unit: Unit) {
+        self.value = total.map(|total| match total {
+            0 => 1.0,
+            total => (current as f64 / total as f64) as f32,
+        });
+        self.amount = Some(Amount {
+            current,
+            total,
+            unit,
+        });
+    }
+
+    /// Set the status message of this progress.
+    pub fn set_message<S: Into<String>>(&mut self, message: S) {
+        self.message = Some(message.into());
+    }
+
+    /// Remove the status message of this progress.
+    pub fn unset_message(&mut self) {
+        self.message = None;
+    }
+
+    /// The progress made, guaranteed to be a float value between 0 and 1. Values out
+    /// of this range are clamped. An indeterminate progress has the value 0.
+    ///
+    /// The value of a progress with children is the weighted average of their values,
+    /// indeterminate children count as not started.
+    pub fn value(&self) -> f32 {
+        if self.children.is_empty() {
+            return clamp(self.value.unwrap_or(0.0), 0.0, 1.0);
+        }
+
+        let total = self.children.iter().map(|(weight, _)| weight).sum::<f32>();
+        if total <= 0.0 {
+            return 0.0;
+        }
+
+        let done = self
+            .children
+            .iter()
+            .map(|(weight, child)| weight * child.value())
+            .sum::<f32>();
+        clamp(done / total, 0.0, 1.0)
+    }
+
+    /// Whether the progress made cannot be told, because the progress has been created
+    /// `indeterminate` or the total amount is unknown. A progress with children is
+    /// indeterminate if all of its children are.
+    pub fn is_indeterminate(&self) -> bool {
+        if self.children.is_empty() {
+            self.value.is_none()
+        } else {
+            self.children
+                .iter()
+                .all(|(_, child)| child.is_indeterminate())
+        }
+    }
+
+    /// Whether this progress is currently worked on, i.e. it has been started but is
+    /// not complete yet. An indeterminate progress is always active.
+    pub fn is_active(&self) -> bool {
+        self.is_indeterminate() || (self.value() > 0.0 && self.value() < 1.0)
+    }
+
+    /// The child tasks of this progress together with their weights.
+    pub fn children(&self) -> impl Iterator<Item = (f32, &Progress)> {
+        self.children.iter().map(|(weight, child)| (*weight, child))
+    }
+
+    pub(crate) fn child_mut(&mut self, idx: usize) -> &mut Progress {
+        &mut self.children[idx].1
+    }
+
+    pub(crate) fn child_count(&self) -> usize {
+        self.children.len()
+    }
+
+    /// The current amount done, if the progress has been reported as an amount.
+    pub fn current(&self) -> Option<u64> {
+        self.amount.as_ref().map(|amount| amount.current)
+    }
+
+    /// The total amount to be done, if the progress has been reported as an amount
+    /// and the total is known.
+    pub fn total(&self) -> Option<u64> {
+        self.amount.as_ref().and_then(|amount| amount.total)
+    }
+
+    /// The unit of the amounts, if the progress has been reported as an amount.
+    pub fn unit(&self) -> Option<&Unit> {
+        self.amount.as_ref().map(|amount| &amount.unit)
+    }
+
+    /// The current and the total amount formatted in their unit, like
+    /// `34.2 MiB / 120 MiB` or `1,204 / 5,000 rows`. Only the current amount is
+    /// shown if the total is unknown.
+    pub fn format_amount(&self) -> Option<String> {
+        let Amount {
+            current,
+            total,
+            unit,
+        } = self.amount.as_ref()?;
+
+        Some(match (total, unit) {
+            (None, unit) => unit.format(*current),
+            (Some(total), Unit::Bytes) => {
+                format!("{} / {}", unit.format(*current), unit.format(*total))
+            }
+            (Some(total), Unit::Items(name)) => {
+                format!("{} / {} {}", thousands(*current), thousands(*total), name)
+            }
+        })
+    }
+
+    /// The status message of this progress, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// The smoothed rate of progress in fractions per second, once it has been
+    /// estimated by the view.
+    pub fn rate(&self) -> Option<f32> {
+        self.rate
+    }
+
+    /// The estimated time left until the progress is complete, once the view
+    /// measured a rate of progress.
+    pub fn eta(&self) -> Option<Duration> {
+        self.eta
+    }
+
+    pub(crate) fn set_estimate(&mut self, rate: Option<f32>, eta: Option<Duration>) {
+        self.rate = rate;
+        self.eta = eta;
+    }
+}
+
+impl Default for Progress {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl From<f32> for Progress {
+    fn from(value: f32) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Unit {
+    /// Countable items with the given name, like `rows` or `files`.
+    pub fn items<S: Into<String>>(name: S) -> Self {
+        Unit::Items(name.into())
+    }
+
+    /// Format `amount` in this unit, like `34.2 MiB` or `1,204 rows`.
+    pub fn format(&self, amount: u64) -> String {
+        match self {
+            Unit::Bytes => bytes(amount),
+            Unit::Items(name) => format!("{} {}", thousands(amount), name),
+        }
+    }
+}
+
+// `amount` with binary prefixes and at most one decimal, like `34.2 MiB`
+fn bytes(amount: u64) -> String {
+    const PREFIXES: [&str; 6] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+
+    let mut value = amount as f64;
+    let mut prefix = 0;
+    while value >= 1024.0 && prefix < PREFIXES.len() - 1 {
+        value /= 1024.0;
+        prefix += 1;
+    }
+
+    let value = format!("{:.1}", value);
+    format!("{} {}", value.trim_end_matches(".0"), PREFIXES[prefix])
+}
+
+// `amount` with thousands separators, like `1,204`
+fn thousands(amount: u64) -> String {
+    let digits = amount.to_string();
+    let mut result = String::new();
+    for (idx, digit) in digits.chars().enumerate() {
+        if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+            result.push(',');
+        }
+        result.push(digit);
+    }
+
+    result
+}
@@ -0,0 +1,34 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+
+/// Call `f`, catching a panic of the user-supplied code it runs. The panic is handed
+/// to the view, which either shows it as an error or resumes it.
+pub(crate) fn catch<R>(f: impl FnOnce() -> R) -> thread::Result<R> {
+    // the state of a panicking loader is never looked at again
+    panic::catch_unwind(AssertUnwindSafe(f))
+}
+
+/// A poll function resuming the panic with `payload` on the cursive thread, for
+/// loaders which panicked before they could return one.
+pub(crate) fn resume_later<S>(payload: Box<dyn Any + Send>) -> impl FnMut() -> S {
+    let mut payload = Some(payload);
+    move || match payload.take() {
+        Some(payload) => panic::resume_unwind(payload),
+        // a panic ends the polling
+        None => unreachable!("polled again after a panic"),
+    }
+}
+
+/// The error message for a panic with `payload`.
+pub(crate) fn message(payload: &(dyn Any + Send)) -> String {
+    let msg = match payload.downcast_ref::<&str>() {
+        Some(msg) => Some(*msg),
+        None => payload.downcast_ref::<String>().map(String::as_str),
+    };
+
+    match msg {
+        Some(msg) => format!("Loading panicked: {}", msg),
+        None => "Loading panicked".to_string(),
+    }
+}
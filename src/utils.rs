@@ -1,4 +1,132 @@
+use std::time::Duration;
+
+use cursive_core::align::HAlign;
+use cursive_core::theme::ColorStyle;
+use cursive_core::utils::markup::StyledString;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
 /// Repeat the string `s` `n` times by concatenating.
 pub fn repeat_str<S: Into<String> + Clone>(s: S, n: usize) -> String {
-    std::iter::repeat(s.into()).take(n).collect::<String>()
+    s.into().repeat(n)
+}
+
+/// A line centered in `width` showing the `remaining` time of a timeout, to be
+/// appended below a loading animation.
+pub fn countdown_line(remaining: Duration, width: usize) -> String {
+    centered_line(&format!("{}s remaining", ceil_secs(remaining)), width)
+}
+
+/// A line centered in `width` showing when the next `attempt` of `max_attempts`
+/// starts, to be appended below a loading animation.
+pub fn retry_line(
+    remaining: Duration,
+    attempt: usize,
+    max_attempts: usize,
+    width: usize,
+) -> String {
+    let text = format!(
+        "retrying in {}s (attempt {}/{})",
+        ceil_secs(remaining),
+        attempt,
+        max_attempts
+    );
+    centered_line(&text, width)
+}
+
+/// A line centered in `width` showing the first line of the status message `msg`,
+/// to be appended below a progress bar.
+pub fn message_line(msg: &str, width: usize) -> String {
+    centered_line(&summary_line(msg, width), width)
+}
+
+/// The `progress` in percent followed by the estimated time left, like `42% · 12s left`.
+pub fn estimate(progress: f32, eta: Duration) -> String {
+    format!(
+        "{:.0}% · {} left",
+        (progress * 100.0).floor(),
+        human_duration(eta)
+    )
+}
+
+/// A badge showing the first line of `msg`, to be drawn over the content of a
+/// view which failed to reload.
+pub fn error_badge(msg: &str) -> StyledString {
+    let summary = msg.lines().next().unwrap_or_default();
+    StyledString::styled(format!(" ⚠ {} ", summary), ColorStyle::highlight())
+}
+
+/// The first line of `msg`, shortened to `width` columns, to be revealed by an
+/// error animation.
+pub fn summary_line(msg: &str, width: usize) -> String {
+    let line = msg.lines().next().unwrap_or_default();
+    if line.width() <= width {
+        return line.to_string();
+    }
+
+    format!("{}…", prefix(line, width.saturating_sub(1)))
+}
+
+/// `text` centered in exactly `width` columns, if it is not wider.
+pub fn center(text: &str, width: usize) -> String {
+    let text_width = text.width();
+    let offset = HAlign::Center.get_offset(text_width, width);
+    format!(
+        "{}{}{}",
+        repeat_str(" ", offset),
+        text,
+        repeat_str(" ", width.saturating_sub(offset + text_width))
+    )
+}
+
+/// `text` cut to exactly `width` columns without splitting a grapheme cluster. A
+/// wide character which does not fit completely is replaced by spaces.
+pub fn cut(text: &str, width: usize) -> String {
+    let prefix = prefix(text, width);
+    format!(
+        "{}{}",
+        prefix,
+        repeat_str(" ", width.saturating_sub(prefix.width()))
+    )
+}
+
+/// Whether `msg` does not fit into the summary line of an error animation.
+pub fn has_details(msg: &str, width: usize) -> bool {
+    let msg = msg.trim_end();
+    msg.lines().count() > 1 || summary_line(msg, width) != msg
+}
+
+fn centered_line(text: &str, width: usize) -> String {
+    let offset = repeat_str(" ", HAlign::Center.get_offset(text.width(), width));
+    format!("\n{}{}", offset, text)
+}
+
+// the longest prefix of whole grapheme clusters fitting into `width` columns
+fn prefix(text: &str, width: usize) -> &str {
+    let mut used = 0;
+    let mut end = 0;
+    for (idx, grapheme) in text.grapheme_indices(true) {
+        used += grapheme.width();
+        if used > width {
+            break;
+        }
+        end = idx + grapheme.len();
+    }
+
+    &text[..end]
+}
+
+// a coarse duration like `12s`, `3m 12s` or `1h 5m`
+fn human_duration(duration: Duration) -> String {
+    let secs = ceil_secs(duration);
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m {}s", secs / 60, secs % 60),
+        _ => format!("{}h {}m", secs / 3600, secs % 3600 / 60),
+    }
+}
+
+// round up, so a countdown never shows zero seconds while still waiting
+fn ceil_secs(duration: Duration) -> u128 {
+    duration.as_millis().div_ceil(1000)
 }